@@ -0,0 +1,129 @@
+use ansi_term::Color;
+
+use crate::config::Config;
+use crate::style::Style;
+
+/// The minimum contrast ratio between text and background required by WCAG level AA.
+const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+/// Warn about every style whose foreground and background colors have a contrast ratio below
+/// WCAG AA. Used by --check-contrast.
+pub fn warn_about_low_contrast_styles(config: &Config) {
+    for (name, ratio) in low_contrast_styles(config) {
+        eprintln!(
+            "delta: {} has a contrast ratio of {:.2}:1 between its foreground and background \
+             colors, which is below the recommended minimum of {}:1.",
+            name, ratio, MIN_CONTRAST_RATIO
+        );
+    }
+}
+
+/// Return the name and contrast ratio of each style with both a foreground and a background
+/// color whose contrast ratio is below `MIN_CONTRAST_RATIO`.
+fn low_contrast_styles(config: &Config) -> Vec<(&'static str, f64)> {
+    let styles: [(&str, &Style); 13] = [
+        ("commit-style", &config.commit_style),
+        ("file-style", &config.file_style),
+        ("hunk-header-style", &config.hunk_header_style),
+        ("minus-style", &config.minus_style),
+        ("minus-non-emph-style", &config.minus_non_emph_style),
+        ("minus-emph-style", &config.minus_emph_style),
+        (
+            "minus-empty-line-marker-style",
+            &config.minus_empty_line_marker_style,
+        ),
+        ("zero-style", &config.zero_style),
+        ("plus-style", &config.plus_style),
+        ("plus-non-emph-style", &config.plus_non_emph_style),
+        ("plus-emph-style", &config.plus_emph_style),
+        (
+            "plus-empty-line-marker-style",
+            &config.plus_empty_line_marker_style,
+        ),
+        ("whitespace-error-style", &config.whitespace_error_style),
+    ];
+    styles
+        .iter()
+        .filter_map(|(name, style)| {
+            match (
+                style.ansi_term_style.foreground,
+                style.ansi_term_style.background,
+            ) {
+                (Some(foreground), Some(background)) => {
+                    Some((*name, contrast_ratio(foreground, background)))
+                }
+                _ => None,
+            }
+        })
+        .filter(|(_, ratio)| *ratio < MIN_CONTRAST_RATIO)
+        .collect()
+}
+
+/// The WCAG contrast ratio of two colors, from 1 (no contrast) to 21 (black on white).
+fn contrast_ratio(color_1: Color, color_2: Color) -> f64 {
+    let (luminance_1, luminance_2) = (relative_luminance(color_1), relative_luminance(color_2));
+    (luminance_1.max(luminance_2) + 0.05) / (luminance_1.min(luminance_2) + 0.05)
+}
+
+/// The WCAG relative luminance of `color`, computed from its sRGB components. The 16 ANSI colors
+/// and 256-color palette entries are converted using the standard xterm palette; the actual
+/// colors depend on the terminal.
+fn relative_luminance(color: Color) -> f64 {
+    let (r, g, b) = match color {
+        Color::RGB(r, g, b) => (r, g, b),
+        Color::Fixed(n) => ansi_colours::rgb_from_ansi256(n),
+        Color::Black => ansi_colours::rgb_from_ansi256(0),
+        Color::Red => ansi_colours::rgb_from_ansi256(1),
+        Color::Green => ansi_colours::rgb_from_ansi256(2),
+        Color::Yellow => ansi_colours::rgb_from_ansi256(3),
+        Color::Blue => ansi_colours::rgb_from_ansi256(4),
+        Color::Purple => ansi_colours::rgb_from_ansi256(5),
+        Color::Cyan => ansi_colours::rgb_from_ansi256(6),
+        Color::White => ansi_colours::rgb_from_ansi256(7),
+    };
+    let linearize = |component: u8| {
+        let c = component as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linearize(r) + 0.7152 * linearize(g) + 0.0722 * linearize(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use ansi_term::Color;
+
+    use super::{contrast_ratio, low_contrast_styles};
+    use crate::tests::integration_test_utils;
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::RGB(0, 0, 0);
+        let white = Color::RGB(255, 255, 255);
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 1e-6);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 1e-6);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_low_contrast_styles() {
+        let config = integration_test_utils::make_config_from_args(&[]);
+        assert!(low_contrast_styles(&config).is_empty());
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--minus-style",
+            "#eeeeee #ffffff",
+            "--plus-style",
+            "#000000 #ffffff",
+        ]);
+        let low_contrast_styles = low_contrast_styles(&config);
+        assert_eq!(low_contrast_styles.len(), 1);
+        assert_eq!(low_contrast_styles[0].0, "minus-style");
+
+        let config = integration_test_utils::make_config_from_args(&["--high-contrast"]);
+        assert!(super::low_contrast_styles(&config).is_empty());
+    }
+}
@@ -46,11 +46,19 @@ pub fn truncate_str<'a, 'b>(s: &'a str, display_width: usize, tail: &'b str) ->
     };
     let mut used = measure_text_width(&result_tail);
     let mut result = String::new();
+    // Once a grapheme does not fit, no later text may be added, otherwise narrower graphemes
+    // following a wide one would be displayed out of place. ANSI escape sequences are still
+    // added, so that e.g. a style reset is preserved.
+    let mut is_full = false;
     for (t, is_ansi) in items {
         if !is_ansi {
             for g in t.graphemes(true) {
+                if is_full {
+                    break;
+                }
                 let w = g.width();
                 if used + w > display_width {
+                    is_full = true;
                     break;
                 }
                 result.push_str(g);
@@ -168,6 +176,32 @@ mod tests {
                    measure_text_width("src/ansi/modバー.rs"));
     }
 
+    #[test]
+    fn test_measure_text_width_osc_hyperlink_terminators() {
+        // OSC sequences can be terminated by ST (ESC followed by a backslash) or by BEL.
+        for (url, text) in &[
+            ("", ""),
+            ("file:///a", "a"),
+            (
+                "https://example.com/a/long/path?with=query",
+                "src/ansi/modバー.rs",
+            ),
+        ] {
+            for terminator in &["\x1b\\", "\x07"] {
+                let s = format!(
+                    "\x1b]8;;{url}{t}{text}\x1b]8;;{t}",
+                    url = url,
+                    text = text,
+                    t = terminator
+                );
+                assert_eq!(strip_ansi_codes(&s), *text);
+                assert_eq!(measure_text_width(&s), measure_text_width(text));
+                let styled = format!("\x1b[31m{}\x1b[0m", s);
+                assert_eq!(measure_text_width(&styled), measure_text_width(text));
+            }
+        }
+    }
+
     #[test]
     fn test_parse_first_style() {
         let minus_line_from_unconfigured_git = "\x1b[31m-____\x1b[m\n";
@@ -216,4 +250,53 @@ mod tests {
         assert_eq!(truncate_str("123", 2, "→"), "1→");
         assert_eq!(truncate_str("12ݶ", 1, "ݶ"), "ݶ");
     }
+
+    #[test]
+    fn test_truncate_str_does_not_skip_wide_graphemes() {
+        assert_eq!(truncate_str("バa", 1, ""), "");
+        assert_eq!(
+            truncate_str("\x1b[31mバ\x1b[0ma", 2, "→"),
+            "\x1b[31m\x1b[0m→"
+        );
+    }
+
+    #[test]
+    fn test_truncate_str_width_and_prefix() {
+        let strings = [
+            "",
+            "abc",
+            "バーバー",
+            "aバbー",
+            "e\u{301}e\u{301}e\u{301}",
+            "👨\u{200d}👩\u{200d}👧x",
+            "\x1b[31mバ\x1b[0mab\x1b[32mー\x1b[0m",
+        ];
+        for s in &strings {
+            for tail in &["", "→", "…", "バ"] {
+                for display_width in 0..=(measure_text_width(s) + 2) {
+                    let truncated = truncate_str(s, display_width, tail);
+                    let truncated_text = strip_ansi_codes(&truncated);
+                    let text = truncated_text
+                        .strip_suffix(&*strip_ansi_codes(tail))
+                        .unwrap_or(&truncated_text);
+                    assert!(
+                        measure_text_width(&truncated) <= display_width,
+                        "{:?} truncated to width {} with {:?} is {:?}",
+                        s,
+                        display_width,
+                        tail,
+                        truncated
+                    );
+                    assert!(
+                        strip_ansi_codes(s).starts_with(text),
+                        "{:?} truncated to width {} with {:?} is {:?}",
+                        s,
+                        display_width,
+                        tail,
+                        truncated
+                    );
+                }
+            }
+        }
+    }
 }
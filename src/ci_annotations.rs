@@ -0,0 +1,111 @@
+use std::io::Write;
+
+/// A summary of one hunk, written as a GitHub Actions `::notice` workflow command once all lines
+/// of the hunk have been seen. Used by --github-actions-annotations.
+/// See https://docs.github.com/en/actions/learn-github-actions/workflow-commands-for-github-actions
+#[derive(Debug, PartialEq)]
+pub struct HunkAnnotation {
+    file: String,
+    start: usize,
+    count: usize,
+    n_minus: usize,
+    n_plus: usize,
+}
+
+impl HunkAnnotation {
+    /// Start the annotation of a hunk with the given hunk header `line_numbers`. The annotated
+    /// line range is the one in the new file, unless the file was deleted. Return `None` if the
+    /// hunk header has no line numbers.
+    pub fn new(minus_file: &str, plus_file: &str, line_numbers: &[(usize, usize)]) -> Option<Self> {
+        let (file, &(start, count)) = if plus_file == "/dev/null" {
+            (minus_file, line_numbers.first()?)
+        } else {
+            (plus_file, line_numbers.last()?)
+        };
+        Some(Self {
+            file: file.to_string(),
+            start,
+            count,
+            n_minus: 0,
+            n_plus: 0,
+        })
+    }
+
+    /// Count `line` if it is a removed or added line of the hunk.
+    pub fn count_line(&mut self, line: &str) {
+        match line.chars().next() {
+            Some('-') => self.n_minus += 1,
+            Some('+') => self.n_plus += 1,
+            _ => {}
+        }
+    }
+
+    pub fn write(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        writeln!(writer, "{}", self.format())
+    }
+
+    fn format(&self) -> String {
+        let end = self.start + self.count.saturating_sub(1);
+        format!(
+            "::notice file={},line={},endLine={},title={}::{}",
+            escape_property(&self.file),
+            self.start,
+            end,
+            escape_property(&format!("Hunk at lines {}-{}", self.start, end)),
+            escape_data(&format!(
+                "{} {} removed, {} {} added",
+                self.n_minus,
+                if self.n_minus == 1 { "line" } else { "lines" },
+                self.n_plus,
+                if self.n_plus == 1 { "line" } else { "lines" },
+            )),
+        )
+    }
+}
+
+/// Escape the message of a workflow command.
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a workflow command.
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let mut annotation =
+            HunkAnnotation::new("src/a.rs", "src/a.rs", &[(71, 11), (71, 8)]).unwrap();
+        for line in &["-a", "-b", "-c", " d", "+e"] {
+            annotation.count_line(line);
+        }
+        assert_eq!(
+            annotation.format(),
+            "::notice file=src/a.rs,line=71,endLine=78,title=Hunk at lines 71-78\
+             ::3 lines removed, 1 line added"
+        );
+    }
+
+    #[test]
+    fn test_format_deleted_file() {
+        let annotation = HunkAnnotation::new("a,b.rs", "/dev/null", &[(1, 2), (0, 0)]).unwrap();
+        assert_eq!(
+            annotation.format(),
+            "::notice file=a%2Cb.rs,line=1,endLine=2,title=Hunk at lines 1-2\
+             ::0 lines removed, 0 lines added"
+        );
+    }
+
+    #[test]
+    fn test_new_without_line_numbers() {
+        assert!(HunkAnnotation::new("a.rs", "a.rs", &[]).is_none());
+        assert!(HunkAnnotation::new("a.rs", "/dev/null", &[]).is_none());
+    }
+}
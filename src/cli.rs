@@ -35,6 +35,10 @@ is
     line-numbers = true
     zero-style = dim syntax
 
+To take settings from one specific file instead, use --config <path>. The git config files that
+would otherwise be used are then ignored. Options given on the command line still take precedence
+over settings in that file.
+
 FEATURES
 --------
 A feature is a named collection of delta options in git config. An example is:
@@ -171,6 +175,8 @@ The following options allow the line number display to be customized:
 
 --line-numbers-left-format:  Change the contents of the left column
 --line-numbers-right-format: Change the contents of the right column
+--line-numbers-minus-format: Change the contents of the left column in minus lines
+--line-numbers-plus-format:  Change the contents of the right column in plus lines
 --line-numbers-left-style:   Change the style applied to the left column
 --line-numbers-right-style:  Change the style applied to the right column
 --line-numbers-minus-style:  Change the style applied to line numbers in minus lines
@@ -214,10 +220,58 @@ pub struct Opt {
     #[structopt(short = "n", long = "line-numbers")]
     pub line_numbers: bool,
 
+    /// Number the lines of each hunk from 1, separately for the old and the new version of the
+    /// file, instead of displaying their line numbers in the file. Line numbers are then not
+    /// hyperlinked (see --hyperlinks).
+    #[structopt(long = "line-numbers-relative-to-hunk")]
+    pub line_numbers_relative_to_hunk: bool,
+
+    /// Draw attention to a range of lines, given as START-END (or a single line number), by
+    /// placing a --line-range-style marker in front of them. A removed line is in the range if its
+    /// line number in the old file is; other lines use their line number in the new file. Only
+    /// used when line numbers are displayed.
+    #[structopt(long = "syntax-line-range")]
+    pub syntax_line_range: Option<String>,
+
+    /// Style (foreground, background, attributes) for the marker placed in front of the lines of
+    /// --syntax-line-range. See STYLES section.
+    #[structopt(long = "line-range-style", default_value = "bold yellow")]
+    pub line_range_style: String,
+
+    /// Shade the code of each unchanged line in groups of N columns, alternating between the
+    /// backgrounds of --column-shade-odd and --column-shade-even. This makes the columns of long
+    /// lines of tabular data, such as log files or CSV, easier to follow. Removed and added lines
+    /// keep their background colors. A value of 0 disables the shading.
+    #[structopt(long = "syntax-wrap-color-columns", default_value = "0")]
+    pub syntax_wrap_color_columns: usize,
+
+    /// Style for the 1st, 3rd, 5th, ... group of columns of --syntax-wrap-color-columns. Only its
+    /// background color is used. See STYLES section.
+    #[structopt(long = "column-shade-odd", default_value = "normal")]
+    pub column_shade_odd: String,
+
+    /// Style for the 2nd, 4th, 6th, ... group of columns of --syntax-wrap-color-columns. Only its
+    /// background color is used. See STYLES section.
+    #[structopt(long = "column-shade-even", default_value = "normal #303030")]
+    pub column_shade_even: String,
+
     /// Display a side-by-side diff view instead of the traditional view.
     #[structopt(short = "s", long = "side-by-side")]
     pub side_by_side: bool,
 
+    /// Minimum width of each side-by-side panel. The panels are half of --width (or of the
+    /// terminal width). If that is less than this value, delta falls back to the normal (unified)
+    /// diff view instead of --side-by-side, without a warning. For example, with a value of 20
+    /// the fallback happens in terminals narrower than 40 columns. The default value of 0 never
+    /// falls back, so that --side-by-side output does not depend on the terminal width.
+    #[structopt(long = "min-panel-width", default_value = "0")]
+    pub min_panel_width: usize,
+
+    /// Order of the panels in side-by-side mode: "ltr" displays removed lines on the left and added
+    /// lines on the right, "rtl" displays added lines on the left and removed lines on the right.
+    #[structopt(long = "side-by-side-direction", default_value = "ltr")]
+    pub side_by_side_direction: String,
+
     #[structopt(long = "diff-highlight")]
     /// Emulate diff-highlight (https://github.com/git/git/tree/master/contrib/diff-highlight)
     pub diff_highlight: bool,
@@ -226,6 +280,12 @@ pub struct Opt {
     /// Emulate diff-so-fancy (https://github.com/so-fancy/diff-so-fancy)
     pub diff_so_fancy: bool,
 
+    #[structopt(long = "high-contrast")]
+    /// Use a high-contrast palette: bright colors on black for removed, added and unchanged lines,
+    /// with explicit foreground colors instead of syntax highlighting. Options given explicitly
+    /// still take precedence.
+    pub high_contrast: bool,
+
     #[structopt(long = "navigate")]
     /// Activate diff navigation: use n to jump forwards and N to jump backwards. To change the
     /// file labels used see --file-modified-label, --file-removed-label, --file-added-label,
@@ -256,6 +316,19 @@ pub struct Opt {
     /// does not emit any prefix, so code can be copied directly from delta's output.
     pub keep_plus_minus_markers: bool,
 
+    #[structopt(long = "invert-diff")]
+    /// Display the diff as if it had been made in the opposite direction: removed lines are
+    /// styled as added lines (see --plus-style etc.) and vice versa, and the line number
+    /// placeholders {nm} and {np} are swapped. In side-by-side mode the panels are swapped, so
+    /// that the removed lines are displayed in the panel for added lines.
+    pub invert_diff: bool,
+
+    #[structopt(long = "show-line-endings")]
+    /// Make the line ending of each hunk line visible: LF line endings are shown as ↵, CRLF line
+    /// endings as ↵⬛, and lone CR characters as ←. See --line-ending-style and
+    /// --show-line-endings-on.
+    pub show_line_endings: bool,
+
     /// Display the active values for all Delta options. Style options are displayed with
     /// foreground and background colors. This can be used to experiment with colors by combining
     /// this option with other options such as --minus-style, --zero-style, --plus-style, --light,
@@ -263,6 +336,24 @@ pub struct Opt {
     #[structopt(long = "show-config")]
     pub show_config: bool,
 
+    /// Process the input, but instead of printing the styled diff, print a description of how
+    /// each input line is handled: its state, style, and, in side-by-side mode, its panel and
+    /// whether it is wrapped. Each line is described by a JSON object on a line of its own. This
+    /// can be used to find out why a line is displayed differently from what was expected.
+    #[structopt(long = "dry-run")]
+    pub dry_run: bool,
+
+    /// Warn about styles whose foreground and background colors have a contrast ratio below 4.5:1,
+    /// the minimum recommended for text by the WCAG accessibility guidelines. Named and 256-color
+    /// palette colors are assumed to have their standard xterm values.
+    #[structopt(long = "check-contrast")]
+    pub check_contrast: bool,
+
+    /// List the builtin features (see --features), each with a description and the options it
+    /// sets.
+    #[structopt(long = "list-features")]
+    pub list_features: bool,
+
     /// List supported languages and associated file extensions.
     #[structopt(long = "list-languages")]
     pub list_languages: bool,
@@ -294,6 +385,12 @@ pub struct Opt {
     /// Do not take any settings from git config. See GIT CONFIG section.
     pub no_gitconfig: bool,
 
+    #[structopt(long = "config", parse(from_os_str))]
+    /// Read settings from this file, which uses git config syntax, instead of from the git config
+    /// files that are normally used (~/.gitconfig, the repository's config, etc). Options given on
+    /// the command line still take precedence over settings in this file. See GIT CONFIG section.
+    pub config: Option<PathBuf>,
+
     #[structopt(long = "raw")]
     /// Do not alter the input in any way. This is mainly intended for testing delta.
     pub raw: bool,
@@ -304,12 +401,49 @@ pub struct Opt {
     /// delta.
     pub color_only: bool,
 
+    #[structopt(long = "output-format", default_value = "delta")]
+    /// Format of the output. "delta" displays the diff as configured by the other options;
+    /// "context-diff" converts it to the traditional context diff format, as produced by
+    /// `diff -c`, for tools which consume that format. In a context diff, lines of a change which
+    /// both removes and adds lines are marked with '!': these lines are syntax-highlighted.
+    pub output_format: String,
+
+    #[structopt(long = "copy-mode")]
+    /// Write plain text without any ANSI escape sequences, suitable for piping to the clipboard
+    /// (e.g. `git diff | delta --copy-mode | pbcopy`) and pasting into a chat message or a pull
+    /// request comment. See --copy-mode-format.
+    pub copy_mode: bool,
+
+    #[structopt(long = "copy-mode-format", default_value = "diff")]
+    /// Format of the --copy-mode output. "diff" writes the unified diff input, with its +/-
+    /// markers; "markdown" writes it inside a Markdown ```diff code block; "plain" writes delta's
+    /// normal output with colors and trailing whitespace removed.
+    pub copy_mode_format: String,
+
+    #[structopt(long = "github-actions-annotations")]
+    /// Write a GitHub Actions `::notice` workflow command to stderr for every hunk, giving its
+    /// file, line range, and numbers of removed and added lines. When delta runs as a CI step,
+    /// these are displayed as annotations of the pull request diff.
+    pub github_actions_annotations: bool,
+
     ////////////////////////////////////////////////////////////////////////////////////////////
     #[structopt(long = "features", default_value = "", env = "DELTA_FEATURES")]
     /// Name of delta features to use (space-separated). A feature is a named collection of delta
     /// options in ~/.gitconfig. See FEATURES section.
     pub features: String,
 
+    #[structopt(long = "no-feature", default_value = "")]
+    /// Name of delta features to disable (space-separated). These are removed from the active
+    /// features after all features have been gathered, so a feature enabled in ~/.gitconfig can be
+    /// turned off without repeating the rest of the features list.
+    pub no_feature: String,
+
+    #[structopt(long = "feature-priority", default_value = "")]
+    /// Name of delta features (space-separated), in order of decreasing priority. When several
+    /// features set the same option, the value from the feature listed first here wins over those
+    /// listed later, and over any feature not listed. See FEATURES section.
+    pub feature_priority: String,
+
     #[structopt(long = "syntax-theme", env = "BAT_THEME")]
     /// The code syntax-highlighting theme to use. Use --show-syntax-themes to demo available
     /// themes. If the syntax-highlighting theme is not set using this option, it will be taken
@@ -317,6 +451,12 @@ pub struct Opt {
     /// --syntax-theme=none disables all syntax highlighting.
     pub syntax_theme: Option<String>,
 
+    #[structopt(long = "themes-dir")]
+    /// Directory containing additional syntax-highlighting themes (.tmTheme files), which can then
+    /// be selected with --syntax-theme by file name (without the extension). A theme with the
+    /// same name as a built-in theme replaces it.
+    pub themes_dir: Option<String>,
+
     #[structopt(long = "minus-style", default_value = "normal auto")]
     /// Style (foreground, background, attributes) for removed lines. See STYLES section.
     pub minus_style: String,
@@ -329,6 +469,18 @@ pub struct Opt {
     /// Style (foreground, background, attributes) for added lines. See STYLES section.
     pub plus_style: String,
 
+    #[structopt(long = "file-added-line-style", default_value = "")]
+    /// Style (foreground, background, attributes) for the lines of an added file, used instead of
+    /// plus-style, so that wholesale additions stand out from modifications. 'auto' refers to
+    /// plus-style. If empty, plus-style is used. See STYLES section.
+    pub file_added_line_style: String,
+
+    #[structopt(long = "file-deleted-line-style", default_value = "")]
+    /// Style (foreground, background, attributes) for the lines of a deleted file, used instead of
+    /// minus-style. 'auto' refers to minus-style. If empty, minus-style is used. See STYLES
+    /// section.
+    pub file_deleted_line_style: String,
+
     #[structopt(long = "minus-emph-style", default_value = "normal auto")]
     /// Style (foreground, background, attributes) for emphasized sections of removed lines. See
     /// STYLES section.
@@ -357,13 +509,25 @@ pub struct Opt {
     #[structopt(long = "commit-decoration-style", default_value = "")]
     /// Style (foreground, background, attributes) for the commit hash decoration. See STYLES
     /// section. The style string should contain one of the special attributes 'box', 'ul'
-    /// (underline), 'ol' (overline), or the combination 'ul ol'.
+    /// (underline, or 'bottom-line'), 'ol' (overline, or 'top-line'), or the combination 'ul ol'.
     pub commit_decoration_style: String,
 
     /// The regular expression used to identify the commit line when parsing git output.
     #[structopt(long = "commit-regex", default_value = r"^commit ")]
     pub commit_regex: String,
 
+    /// A strftime-style format string (e.g. "%Y-%m-%d %H:%M") used to reformat the Date: line of
+    /// commit headers, as output by e.g. `git log`. Only git's default date format is recognized;
+    /// other Date: lines are left unchanged. See --commit-timestamp-timezone.
+    #[structopt(long = "commit-timestamp-format")]
+    pub commit_timestamp_format: Option<String>,
+
+    /// The timezone in which to display the Date: line of commit headers. Options are "original"
+    /// (the timezone recorded in the commit), "local" and "utc". Unless --commit-timestamp-format
+    /// is given, the date is otherwise displayed in git's default format.
+    #[structopt(long = "commit-timestamp-timezone", default_value = "original")]
+    pub commit_timestamp_timezone: String,
+
     #[structopt(long = "file-style", default_value = "blue")]
     /// Style (foreground, background, attributes) for the file section. See STYLES section. The
     /// style 'omit' can be used to remove the file section from the output.
@@ -371,10 +535,43 @@ pub struct Opt {
 
     #[structopt(long = "file-decoration-style", default_value = "blue ul")]
     /// Style (foreground, background, attributes) for the file decoration. See STYLES section. The
-    /// style string should contain one of the special attributes 'box', 'ul' (underline), 'ol'
-    /// (overline), or the combination 'ul ol'.
+    /// style string should contain one of the special attributes 'box', 'ul' (underline, or
+    /// 'bottom-line'), 'ol' (overline, or 'top-line'), or the combination 'ul ol'.
     pub file_decoration_style: String,
 
+    #[structopt(long = "binary-diff-style", default_value = "")]
+    /// Style (foreground, background, attributes) for the "Binary files ... differ" notice. See
+    /// STYLES section. The decoration is taken from file-decoration-style. If not set, the notice
+    /// is displayed in file-style.
+    pub binary_diff_style: String,
+
+    #[structopt(long = "submodule-style", default_value = "")]
+    /// Style (foreground, background, attributes) for the "Submodule ..." lines which git emits
+    /// with --submodule=log (or diff.submodule = log). See STYLES section. The decoration is taken
+    /// from file-decoration-style. If not set, these lines are displayed in file-style.
+    pub submodule_style: String,
+
+    #[structopt(long = "suppress-diff-binary-notice")]
+    /// Remove the "Binary files ... differ" notice from the output. Ignored in --color-only mode,
+    /// which must not remove lines.
+    pub suppress_diff_binary_notice: bool,
+
+    #[structopt(long = "file-style-map", default_value = "")]
+    /// Comma-separated list of PATTERN=STYLE overrides of file-style, for example
+    /// "rs=bold red,*.test.rs=dim". A PATTERN without wildcards is a file extension; otherwise it
+    /// is a glob in which '*' and '?' match file name characters, matched against the file name
+    /// (or the whole path if PATTERN contains '/'). Matching is case-insensitive and the first
+    /// matching PATTERN is used.
+    pub file_style_map: String,
+
+    /// A PATTERN=REPLACEMENT pair (split at the first '=') rewriting the file paths displayed in
+    /// file and hunk headers: the first match of the regular expression PATTERN is replaced by
+    /// REPLACEMENT, which may refer to capture groups as $1, $2, etc. May be given multiple times;
+    /// the transforms are applied in order. Paths used for syntax detection and hyperlinks are
+    /// not affected.
+    #[structopt(long = "file-path-regex-transform", number_of_values = 1)]
+    pub file_path_regex_transform: Vec<String>,
+
     /// Format string for commit hyperlinks (requires --hyperlinks). The
     /// placeholder "{commit}" will be replaced by the commit hash. For example:
     /// --hyperlinks-commit-link-format='https://mygitrepo/{commit}/'
@@ -402,22 +599,65 @@ pub struct Opt {
     /// section from the output.
     pub hunk_header_style: String,
 
+    #[structopt(long = "hunk-header-style-by-language", default_value = "")]
+    /// Comma-separated list of EXTENSION=STYLE overrides of hunk-header-style, applied according
+    /// to the extension of the file being diffed, for example "rs=bold red,py=yellow". The
+    /// EXTENSION '*' sets the style for files without a more specific entry.
+    pub hunk_header_style_by_language: String,
+
     #[structopt(long = "hunk-header-file-style", default_value = "blue")]
     /// Style (foreground, background, attributes) for the file path part of the hunk-header. See
     /// STYLES section. The file path will only be displayed if hunk-header-style contains the
     /// 'file' special attribute.
     pub hunk_header_file_style: String,
 
+    #[structopt(long = "hunk-header-file-path")]
+    /// Include the path of the current file in every hunk header. This is equivalent to adding the
+    /// 'file' special attribute to hunk-header-style.
+    pub hunk_header_file_path: bool,
+
+    #[structopt(long = "hunk-header-file-path-format", default_value = "{file}")]
+    /// Format string for the file path displayed in hunk headers. The placeholder {file} is
+    /// replaced with the full file path, {file_name} with the final component of the path, and
+    /// {scope} with the syntax highlighting scope of the file (e.g. "source.rust"), or its
+    /// extension if no syntax was found.
+    pub hunk_header_file_path_format: String,
+
     #[structopt(long = "hunk-header-line-number-style", default_value = "blue")]
     /// Style (foreground, background, attributes) for the line number part of the hunk-header. See
     /// STYLES section. The line number will only be displayed if hunk-header-style contains the
     /// 'line-number' special attribute.
     pub hunk_header_line_number_style: String,
 
+    #[structopt(long = "hunk-header-context-max-length", default_value = "0")]
+    /// Maximum number of characters of the code fragment (the "function context" which git
+    /// appends to the @@ line) to display in the hunk header. Longer code fragments are
+    /// truncated. The default value of zero means no limit, so that the code fragment is displayed
+    /// as git provides it (git already limits its length).
+    pub hunk_header_context_max_length: usize,
+
+    #[structopt(long = "hunk-header-context-position", default_value = "right")]
+    /// Where the code fragment is displayed in the hunk header relative to the file path and line
+    /// number: "left" or "right".
+    pub hunk_header_context_position: String,
+
+    #[structopt(long = "hunk-header-context-style", default_value = "")]
+    /// Style (foreground, background, attributes) for the code fragment part of the hunk-header.
+    /// See STYLES section. If not set, the code fragment is displayed in hunk-header-style.
+    pub hunk_header_context_style: String,
+
+    #[structopt(long = "hunk-header-marker-style", default_value = "")]
+    /// Style (foreground, background, attributes) for the @@ markers of a hunk header displayed
+    /// raw (hunk-header-style = raw). See STYLES section. If set, the raw hunk header is repainted
+    /// in three parts: the markers in this style, the line ranges in
+    /// hunk-header-line-number-style, and the code fragment in hunk-header-context-style. If not
+    /// set, the raw hunk header is displayed as git colored it.
+    pub hunk_header_marker_style: String,
+
     #[structopt(long = "hunk-header-decoration-style", default_value = "blue box")]
     /// Style (foreground, background, attributes) for the hunk-header decoration. See STYLES
     /// section. The style string should contain one of the special attributes 'box', 'ul'
-    /// (underline), 'ol' (overline), or the combination 'ul ol'.
+    /// (underline, or 'bottom-line'), 'ol' (overline, or 'top-line'), or the combination 'ul ol'.
     pub hunk_header_decoration_style: String,
 
     /// Default language used for syntax highlighting when this cannot be
@@ -426,12 +666,41 @@ pub struct Opt {
     #[structopt(long = "default-language")]
     pub default_language: Option<String>,
 
+    /// Regular expression matching the text (without the +/- marker) of hunk lines which must
+    /// not be displayed, e.g. because they contain passwords. The content of a matching line is
+    /// replaced by --redact-placeholder. May be given multiple times.
+    #[structopt(long = "redact-lines", number_of_values = 1)]
+    pub redact_lines: Vec<String>,
+
+    /// Text displayed instead of the content of lines matched by --redact-lines.
+    #[structopt(long = "redact-placeholder", default_value = "[REDACTED]")]
+    pub redact_placeholder: String,
+
+    #[structopt(long = "redact-style", default_value = "bold")]
+    /// Style (foreground, background, attributes) for --redact-placeholder. See STYLES section.
+    pub redact_style: String,
+
     #[structopt(long = "inline-hint-style", default_value = "blue")]
     /// Style (foreground, background, attributes) for content added by delta to
     /// the original diff such as special characters to highlight tabs, and the
     /// symbols used to indicate wrapped lines. See STYLES section.
     pub inline_hint_style: String,
 
+    #[structopt(long = "inline-hint-color")]
+    /// Foreground color for the content styled by inline-hint-style. This overrides the
+    /// foreground color of inline-hint-style, leaving its other components unchanged.
+    pub inline_hint_color: Option<String>,
+
+    #[structopt(long = "line-ending-style", default_value = "blue")]
+    /// Style (foreground, background, attributes) for the LF and CRLF line ending symbols
+    /// displayed by --show-line-endings. See STYLES section.
+    pub line_ending_style: String,
+
+    #[structopt(long = "show-line-endings-on", default_value = "all")]
+    /// Which hunk lines should have their line endings displayed by --show-line-endings. Options
+    /// are "minus", "plus", "context" (unchanged lines), and "all".
+    pub show_line_endings_on: String,
+
     /// The regular expression used to decide what a word is for the within-line highlight
     /// algorithm. For less fine-grained matching than the default try --word-diff-regex="\S+"
     /// --max-line-distance=1.0 (this is more similar to `git --word-diff`).
@@ -473,6 +742,49 @@ pub struct Opt {
     #[structopt(long = "line-numbers-right-format", default_value = "{np:^4}│")]
     pub line_numbers_right_format: String,
 
+    /// Format string which replaces --line-numbers-left-format for minus (removed) lines, for
+    /// example "{nm:^4}*" to mark the line numbers of removed lines. It should have the same width
+    /// as --line-numbers-left-format, so that the code stays aligned. Not used if empty.
+    #[structopt(long = "line-numbers-minus-format", default_value = "")]
+    pub line_numbers_minus_format: String,
+
+    /// Format string which replaces --line-numbers-right-format for plus (added) lines, for
+    /// example "{np:^4}*" to mark the line numbers of added lines. It should have the same width
+    /// as --line-numbers-right-format, so that the code stays aligned. Not used if empty.
+    #[structopt(long = "line-numbers-plus-format", default_value = "")]
+    pub line_numbers_plus_format: String,
+
+    /// String displayed between the two panels in side-by-side mode. Empty by default, since the
+    /// line number formats of side-by-side mode already begin each panel with "│". For example,
+    /// --line-numbers-separator-format='┃' --line-numbers-right-format='{np:^4}│' replaces that
+    /// character with a heavier line.
+    #[structopt(long = "line-numbers-separator-format", default_value = "")]
+    pub line_numbers_separator_format: String,
+
+    /// Style (foreground, background, attributes) for --line-numbers-separator-format. Defaults to
+    /// --line-numbers-right-style. See STYLES section.
+    #[structopt(long = "line-numbers-separator-style", default_value = "")]
+    pub line_numbers_separator_style: String,
+
+    /// Style (foreground, background, attributes) for the column separators, i.e. the "│"
+    /// characters in --line-numbers-left-format and --line-numbers-right-format, in side-by-side
+    /// mode. By default they are styled like the rest of the format string, see
+    /// --line-numbers-left-style and --line-numbers-right-style. See STYLES section.
+    #[structopt(long = "side-by-side-column-separator-style", default_value = "")]
+    pub side_by_side_column_separator_style: String,
+
+    /// Style for the column separator at the left edge of the left panel, i.e. a "│" at the start
+    /// of the line number format of that panel. Defaults to
+    /// --side-by-side-column-separator-style. See STYLES section.
+    #[structopt(long = "separator-before-left-panel", default_value = "")]
+    pub separator_before_left_panel: String,
+
+    /// Style for the column separator between the two panels, i.e. a "│" at the start of the line
+    /// number format of the right panel. Defaults to --side-by-side-column-separator-style. See
+    /// STYLES section.
+    #[structopt(long = "separator-between-panels", default_value = "")]
+    pub separator_between_panels: String,
+
     /// Style (foreground, background, attributes) for the left column of line numbers. See STYLES
     /// and LINE NUMBERS sections.
     #[structopt(long = "line-numbers-left-style", default_value = "auto")]
@@ -484,8 +796,8 @@ pub struct Opt {
     pub line_numbers_right_style: String,
 
     /// How often a line should be wrapped if it does not fit. Zero means to never wrap. Any content
-    /// which does not fit will be truncated. A value of "unlimited" means a line will be wrapped
-    /// as many times as required.
+    /// which does not fit will be truncated. A value of "unlimited" (or "none", i.e. no limit)
+    /// means a line will be wrapped as many times as required.
     #[structopt(long = "wrap-max-lines", default_value = "2")]
     pub wrap_max_lines: String,
 
@@ -509,6 +821,18 @@ pub struct Opt {
     #[structopt(long = "wrap-right-prefix-symbol", default_value = "…")]
     pub wrap_right_prefix_symbol: String,
 
+    /// Symbol replacing the last character of a line which does not fit into the panel even after
+    /// being wrapped onto --wrap-max-lines lines. If empty, the symbol used for any other
+    /// truncated line is used, so that these lines look the same.
+    #[structopt(long = "wrap-overflow-symbol", default_value = "")]
+    pub wrap_overflow_symbol: String,
+
+    /// How the newline at the end of a wrapped line is displayed. With "absorb" it takes up no
+    /// space, with "visible" a ↵ is shown in front of it, and with "strip" it is removed from
+    /// the output.
+    #[structopt(long = "wrap-indicator-newline-behavior", default_value = "absorb")]
+    pub wrap_indicator_newline_behavior: String,
+
     #[structopt(long = "file-modified-label", default_value = "")]
     /// Text to display in front of a modified file path.
     pub file_modified_label: String,
@@ -541,7 +865,9 @@ pub struct Opt {
 
     /// How to extend the background color to the end of the line in side-by-side mode. Can
     /// be ansi (default) or spaces (default if output is not to a terminal). Has no effect
-    /// if --width=variable is given.
+    /// if --width=variable is given. Alternatively, pattern:<char> fills with the given
+    /// character (of display width 1 or 2), which also marks otherwise empty panels, e.g.
+    /// --line-fill-method='pattern:·'.
     #[structopt(long = "line-fill-method")]
     pub line_fill_method: Option<String>,
 
@@ -558,6 +884,25 @@ pub struct Opt {
     #[structopt(long = "diff-stat-align-width", default_value = "48")]
     pub diff_stat_align_width: usize,
 
+    /// How to draw the bar of each file entry in a diff stat section (the output of e.g. `git diff
+    /// --stat`). With "git" (the default), file entries are displayed as output by git. With
+    /// "plus-minus", "line" or "shade", delta redraws the bar using +/- characters, ─ characters
+    /// or ▒ characters respectively, styled with plus-style and minus-style. See --file-stat-width
+    /// and --file-stat-bar-width.
+    #[structopt(long = "file-stat-bar-style", default_value = "git")]
+    pub file_stat_bar_style: String,
+
+    /// Width of the file name column of a diff stat redrawn by delta. Longer file paths are
+    /// shortened. Only used if --file-stat-bar-style is not "git".
+    #[structopt(long = "file-stat-width", default_value = "50")]
+    pub file_stat_width: usize,
+
+    /// Maximum width of the bar of a diff stat redrawn by delta. The bars are scaled so that the
+    /// file with the most changes fills this width. Only used if --file-stat-bar-style is not
+    /// "git".
+    #[structopt(long = "file-stat-bar-width", default_value = "20")]
+    pub file_stat_bar_width: usize,
+
     /// The number of spaces to replace tab characters with. Use --tabs=0 to pass tab characters
     /// through directly, but note that in that case delta will calculate line widths assuming tabs
     /// occupy one character's width on the screen: if your terminal renders tabs as more than than
@@ -565,6 +910,48 @@ pub struct Opt {
     #[structopt(long = "tabs", default_value = "4")]
     pub tab_width: usize,
 
+    /// Number of blank lines to insert before the first changed line, and after the last changed
+    /// line, of each hunk. The blank lines are styled as unchanged lines (see --zero-style) and
+    /// have empty line number fields.
+    #[structopt(long = "syntax-padding", default_value = "0")]
+    pub syntax_padding: usize,
+
+    #[structopt(long = "syntax-disabled-for", default_value = "")]
+    /// Comma-separated list of file extensions for which syntax highlighting is disabled, for
+    /// example "json,min.js". An extension matches any file path ending with '.' followed by the
+    /// extension, ignoring case. Lines of these files are displayed without syntax colors.
+    pub syntax_disabled_for: String,
+
+    #[structopt(long = "syntax-disabled-for-default")]
+    /// Disable syntax highlighting for extensions of files which are typically generated and for
+    /// which highlighting is mostly noise: lock, map, min.css, min.js and svg. These are added to
+    /// the extensions of --syntax-disabled-for.
+    pub syntax_disabled_for_default: bool,
+
+    #[structopt(long = "syntax-ignore-case")]
+    /// If the extension of a file does not match a language exactly, look it up again in lower
+    /// case, so that for example a file named "NOTES.MD" is highlighted as Markdown.
+    pub syntax_ignore_case: bool,
+
+    /// Maximum number of unchanged lines to display before the first changed line of each hunk.
+    /// Unlike `git diff -U`, this only affects the context preceding the changes: use it with
+    /// --context-after to see more of the code before a change (for example the enclosing
+    /// function signature) than after it. The context that git provides is the upper limit, so
+    /// this is typically combined with a large -U value. Unchanged lines between changes are
+    /// always displayed.
+    #[structopt(long = "context-before")]
+    pub context_before: Option<String>,
+
+    /// Maximum number of unchanged lines to display after the last changed line of each hunk.
+    /// See --context-before.
+    #[structopt(long = "context-after")]
+    pub context_after: Option<String>,
+
+    /// Do not display hunks which consist of unchanged lines only. Such hunks are, for example,
+    /// produced by some tools after a rebase or a formatting-only change.
+    #[structopt(long = "suppress-empty-hunk-context")]
+    pub suppress_empty_hunk_context: bool,
+
     /// Whether to emit 24-bit ("true color") RGB color codes. Options are auto, always, and never.
     /// "auto" means that delta will emit 24-bit color codes if the environment variable COLORTERM
     /// has the value "truecolor" or "24bit". If your terminal application (the application you use
@@ -594,6 +981,27 @@ pub struct Opt {
     #[structopt(long = "paging", default_value = "auto")]
     pub paging_mode: String,
 
+    /// Do not use a pager: a shorthand for --paging=never, as with `git --no-pager`. It takes
+    /// precedence over --paging, and no pager is run even if one is set by DELTA_PAGER, BAT_PAGER
+    /// or PAGER. It cannot be combined with --pager.
+    #[structopt(long = "no-pager", conflicts_with = "pager")]
+    pub no_pager: bool,
+
+    /// Comma-separated list of KEY=VALUE git config settings, e.g. "diff.renames=true". Each one
+    /// is passed as `-c KEY=VALUE` to the git process that generates the diff. This only works
+    /// when delta runs git itself (`delta file_A file_B`) or is configured as git's `core.pager`:
+    /// in the latter case delta re-runs the calling git command with the extra settings
+    /// (currently supported on Linux only). Only commands which just display diffs (diff, log,
+    /// show, stash show, etc.) are re-run; for other commands the settings are ignored.
+    #[structopt(long = "git-config-parameters", default_value = "")]
+    pub git_config_parameters: String,
+
+    /// The diff algorithm used by git: "myers", "minimal", "patience" or "histogram". This is a
+    /// shorthand for adding diff.algorithm=ALGORITHM to --git-config-parameters, and works in the
+    /// same situations.
+    #[structopt(long = "diff-algorithm")]
+    pub diff_algorithm: Option<String>,
+
     /// First file to be compared when delta is being used in diff mode: `delta file_1 file_2` is
     /// equivalent to `diff -u file_1 file_2 | delta`.
     #[structopt(parse(from_os_str))]
@@ -614,6 +1022,18 @@ pub struct Opt {
     #[structopt(long = "--plus-empty-line-marker-style", default_value = "normal auto")]
     pub plus_empty_line_marker_style: String,
 
+    /// Style (foreground, background, attributes) for removed lines which are empty, i.e. blank
+    /// lines. See STYLES section. Defaults to minus-style. The special color 'auto' refers to the
+    /// corresponding color of minus-style.
+    #[structopt(long = "minus-empty-line-style", default_value = "")]
+    pub minus_empty_line_style: String,
+
+    /// Style (foreground, background, attributes) for added lines which are empty, i.e. blank
+    /// lines. See STYLES section. Defaults to plus-style. The special color 'auto' refers to the
+    /// corresponding color of plus-style.
+    #[structopt(long = "plus-empty-line-style", default_value = "")]
+    pub plus_empty_line_style: String,
+
     /// Style for whitespace errors. Defaults to color.diff.whitespace if that is set in git
     /// config, or else 'magenta reverse'.
     #[structopt(long = "whitespace-error-style", default_value = "auto auto")]
@@ -628,18 +1048,26 @@ pub struct Opt {
     /// added/removed.
     pub line_buffer_size: usize,
 
+    #[structopt(long = "output-buffer-size", default_value = "0")]
+    /// Size in kilobytes of a buffer for the output, which is then written to the terminal or pager
+    /// in larger chunks. This can speed up the display of very large diffs, but output appears in
+    /// bursts, and only once the buffer is full. 0 or 1 means unbuffered: every line is written
+    /// as soon as it is painted.
+    pub output_buffer_size: usize,
+
     #[structopt(long = "minus-color")]
-    /// Deprecated: use --minus-style='normal my_background_color'.
-    pub deprecated_minus_background_color: Option<String>,
+    /// Background color for removed lines. This overrides the background color of minus-style,
+    /// leaving its other components unchanged. Use --minus-style to set a full style string.
+    pub minus_color: Option<String>,
 
     #[structopt(long = "minus-emph-color")]
     /// Deprecated: use --minus-emph-style='normal my_background_color'.
     pub deprecated_minus_emph_background_color: Option<String>,
 
     #[structopt(long = "plus-color")]
-    /// Deprecated: Use --plus-style='syntax my_background_color' to change the background color
-    /// while retaining syntax-highlighting.
-    pub deprecated_plus_background_color: Option<String>,
+    /// Background color for added lines. This overrides the background color of plus-style,
+    /// leaving its other components unchanged. Use --plus-style to set a full style string.
+    pub plus_color: Option<String>,
 
     #[structopt(long = "plus-emph-color")]
     /// Deprecated: Use --plus-emph-style='syntax my_background_color' to change the background
@@ -750,6 +1178,10 @@ impl Opt {
         assets: HighlightingAssets,
     ) -> Self {
         let mut opt = Opt::from_clap(&arg_matches);
+        if let Some(path) = &opt.config {
+            let repo = git_config.and_then(|git_config| git_config.repo);
+            git_config = Some(GitConfig::from_file(path, repo));
+        }
         options::rewrite::apply_rewrite_rules(&mut opt, &arg_matches);
         options::set::set_options(&mut opt, &mut git_config, &arg_matches, assets);
         opt.git_config = git_config;
@@ -782,15 +1214,17 @@ lazy_static! {
     static ref IGNORED_OPTION_NAMES: HashSet<&'static str> = vec![
         "deprecated-file-color",
         "deprecated-hunk-style",
-        "deprecated-minus-background-color",
         "deprecated-minus-emph-background-color",
         "deprecated-hunk-color",
         "deprecated-plus-emph-background-color",
-        "deprecated-plus-background-color",
         "deprecated-highlight-minus-lines",
         "deprecated-theme",
+        "check-contrast",
+        "config",
         "deprecated-commit-color",
+        "dry-run",
         "list-languages",
+        "list-features",
         "list-syntax-themes",
         "show-config",
         "show-syntax-themes",
@@ -10,22 +10,24 @@ use crate::fatal;
 use crate::syntect_utils;
 
 pub fn parse_color(s: &str, true_color: bool) -> Option<Color> {
+    try_parse_color(s, true_color)
+        .unwrap_or_else(|| fatal(format!("Invalid color or style attribute: {}", s)))
+}
+
+/// As [`parse_color`], but return `None` instead of exiting if `s` is not a valid color.
+pub fn try_parse_color(s: &str, true_color: bool) -> Option<Option<Color>> {
     if s == "normal" {
-        return None;
+        return Some(None);
     }
-    let die = || {
-        fatal(format!("Invalid color or style attribute: {}", s));
-    };
     let syntect_color = if s.starts_with('#') {
-        SyntectColor::from_str(s).unwrap_or_else(|_| die())
+        SyntectColor::from_str(s).ok()?
     } else {
         s.parse::<u8>()
             .ok()
             .and_then(syntect_utils::syntect_color_from_ansi_number)
-            .or_else(|| syntect_utils::syntect_color_from_ansi_name(s))
-            .unwrap_or_else(die)
+            .or_else(|| syntect_utils::syntect_color_from_ansi_name(s))?
     };
-    to_ansi_color(syntect_color, true_color)
+    Some(to_ansi_color(syntect_color, true_color))
 }
 
 pub fn color_to_string(color: Color) -> String {
@@ -1,32 +1,44 @@
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
 
+use chrono::format::{Item, StrftimeItems};
 use regex::Regex;
 use structopt::clap;
 use syntect::highlighting::Style as SyntectStyle;
 use syntect::highlighting::Theme as SyntaxTheme;
 use syntect::parsing::SyntaxSet;
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
 
 use crate::ansi;
 use crate::bat_utils::output::PagingMode;
 use crate::cli;
 use crate::color;
-use crate::delta::State;
+use crate::delta::{LineEndingsOn, State};
 use crate::env;
 use crate::fatal;
 use crate::features::navigate;
-use crate::features::side_by_side::{self, ansifill, LeftRight};
+use crate::features::side_by_side::{self, ansifill, LeftRight, SideBySideDirection};
 use crate::git_config::{GitConfig, GitConfigEntry};
+use crate::handlers::commit_meta::CommitTimestampTimezone;
+use crate::handlers::diff_stat::DiffStatBarStyle;
+use crate::handlers::hunk_header::HunkHeaderContextPosition;
 use crate::minusplus::MinusPlus;
+use crate::output::copy_mode::CopyModeFormat;
+use crate::output::OutputFormat;
 use crate::paint::BgFillMethod;
 use crate::style::{self, Style};
 use crate::syntect_utils::FromDeltaStyle;
 use crate::tests::TESTING;
-use crate::wrapping::WrapConfig;
+use crate::wrapping::{WrapConfig, WrapNewlineBehavior};
 
 pub const INLINE_SYMBOL_WIDTH_1: usize = 1;
 
+/// Extensions for which --syntax-disabled-for-default disables syntax highlighting.
+const DEFAULT_SYNTAX_DISABLED_FOR: &[&str] = &["lock", "map", "min.css", "min.js", "svg"];
+
 fn remove_percent_suffix(arg: &str) -> &str {
     match &arg.strip_suffix('%') {
         Some(s) => s,
@@ -44,8 +56,19 @@ fn ensure_display_width_1(what: &str, arg: String) -> String {
     }
 }
 
+fn parse_line_fill_pattern(pattern: &str) -> char {
+    let mut chars = pattern.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if matches!(UnicodeWidthChar::width(c), Some(1) | Some(2)) => c,
+        _ => fatal(format!(
+            "Invalid value for line-fill-method, \"pattern:{}\" must be a single character of display width 1 or 2",
+            pattern
+        )),
+    }
+}
+
 fn adapt_wrap_max_lines_argument(arg: String) -> usize {
-    if arg == "∞" || arg == "unlimited" || arg.starts_with("inf") {
+    if arg == "∞" || arg == "unlimited" || arg == "none" || arg.starts_with("inf") {
         0
     } else {
         arg.parse::<usize>()
@@ -54,29 +77,65 @@ fn adapt_wrap_max_lines_argument(arg: String) -> usize {
     }
 }
 
+fn parse_context_argument(arg: &Option<String>, option_name: &str) -> Option<usize> {
+    arg.as_ref().map(|arg| {
+        arg.parse::<usize>().unwrap_or_else(|err| {
+            fatal(format!(
+                "Invalid value for {}: {} ({})",
+                option_name, arg, err
+            ))
+        })
+    })
+}
+
 pub struct Config {
     pub available_terminal_width: usize,
     pub background_color_extends_to_terminal_width: bool,
+    pub binary_diff_style: Option<Style>,
+    pub check_contrast: bool,
+    pub column_shades: Option<(usize, Style, Style)>,
     pub commit_style: Style,
     pub color_only: bool,
     pub commit_regex: Regex,
+    pub commit_timestamp_format: Option<String>,
+    pub commit_timestamp_timezone: CommitTimestampTimezone,
+    pub copy_mode: bool,
+    pub copy_mode_format: CopyModeFormat,
+    pub context_after: Option<usize>,
+    pub context_before: Option<usize>,
     pub cwd_relative_to_repo_root: Option<String>,
     pub decorations_width: cli::Width,
     pub default_language: Option<String>,
     pub diff_stat_align_width: usize,
+    pub dry_run: bool,
     pub error_exit_code: i32,
     pub file_added_label: String,
+    pub file_added_line_style: Option<Style>,
     pub file_copied_label: String,
+    pub file_deleted_line_style: Option<Style>,
     pub file_modified_label: String,
     pub file_removed_label: String,
     pub file_renamed_label: String,
     pub hunk_label: String,
+    pub file_stat_bar_style: DiffStatBarStyle,
+    pub file_stat_bar_width: usize,
+    pub file_stat_width: usize,
     pub file_style: Style,
+    pub file_style_map: Vec<(Regex, Style)>,
+    pub file_path_regex_transforms: Vec<(Regex, String)>,
     pub git_config: Option<GitConfig>,
     pub git_config_entries: HashMap<String, GitConfigEntry>,
+    pub git_config_parameters: Vec<String>,
+    pub github_actions_annotations: bool,
+    pub hunk_header_context_max_length: usize,
+    pub hunk_header_context_position: HunkHeaderContextPosition,
+    pub hunk_header_context_style: Option<Style>,
+    pub hunk_header_file_path_format: String,
     pub hunk_header_file_style: Style,
     pub hunk_header_line_number_style: Style,
+    pub hunk_header_marker_style: Option<Style>,
     pub hunk_header_style: Style,
+    pub hunk_header_style_by_language: HashMap<String, Style>,
     pub hunk_header_style_include_file_path: bool,
     pub hunk_header_style_include_line_number: bool,
     pub hyperlinks: bool,
@@ -84,19 +143,27 @@ pub struct Config {
     pub hyperlinks_file_link_format: String,
     pub inline_hint_style: Style,
     pub inspect_raw_lines: cli::InspectRawLines,
+    pub invert_diff: bool,
     pub keep_plus_minus_markers: bool,
     pub line_fill_method: BgFillMethod,
     pub line_numbers: bool,
     pub line_numbers_format: LeftRight<String>,
+    pub line_numbers_format_minusplus: MinusPlus<Option<String>>,
+    pub line_numbers_relative_to_hunk: bool,
+    pub line_numbers_separator_format: String,
+    pub line_numbers_separator_style: Style,
     pub line_numbers_style_leftright: LeftRight<Style>,
     pub line_numbers_style_minusplus: MinusPlus<Style>,
     pub line_numbers_zero_style: Style,
+    pub line_range_style: Style,
     pub line_buffer_size: usize,
+    pub line_ending_style: Style,
     pub max_line_distance: f64,
     pub max_line_distance_for_naively_paired_lines: f64,
     pub max_line_length: usize,
     pub minus_emph_style: Style,
     pub minus_empty_line_marker_style: Style,
+    pub minus_empty_line_style: Option<Style>,
     pub minus_file: Option<PathBuf>,
     pub minus_non_emph_style: Style,
     pub minus_style: Style,
@@ -104,20 +171,39 @@ pub struct Config {
     pub navigate_regexp: Option<String>,
     pub null_style: Style,
     pub null_syntect_style: SyntectStyle,
+    pub output_buffer_size: usize,
+    pub output_format: OutputFormat,
     pub pager: Option<String>,
     pub paging_mode: PagingMode,
     pub plus_emph_style: Style,
     pub plus_empty_line_marker_style: Style,
+    pub plus_empty_line_style: Option<Style>,
     pub plus_file: Option<PathBuf>,
     pub plus_non_emph_style: Style,
     pub plus_style: Style,
     pub git_minus_style: Style,
     pub git_plus_style: Style,
+    pub redact_lines: Vec<Regex>,
+    pub redact_placeholder: String,
+    pub redact_style: Style,
     pub relative_paths: bool,
+    pub show_line_endings: bool,
+    pub show_line_endings_on: LineEndingsOn,
     pub show_themes: bool,
+    pub separator_before_left_panel_style: Option<Style>,
+    pub separator_between_panels_style: Option<Style>,
     pub side_by_side: bool,
+    pub side_by_side_column_separator_style: Option<Style>,
     pub side_by_side_data: side_by_side::SideBySideData,
+    pub side_by_side_direction: SideBySideDirection,
     pub syntax_dummy_theme: SyntaxTheme,
+    pub submodule_style: Style,
+    pub suppress_diff_binary_notice: bool,
+    pub suppress_empty_hunk_context: bool,
+    pub syntax_disabled_for: HashSet<String>,
+    pub syntax_ignore_case: bool,
+    pub syntax_line_range: Option<(usize, usize)>,
+    pub syntax_padding: usize,
     pub syntax_set: SyntaxSet,
     pub syntax_theme: Option<SyntaxTheme>,
     pub tab_width: usize,
@@ -129,7 +215,95 @@ pub struct Config {
     pub zero_style: Style,
 }
 
+/// An error in the options from which a [`Config`] is built.
+#[derive(Debug)]
+pub enum ConfigError {
+    InvalidRegex {
+        option: &'static str,
+        pattern: String,
+        error: regex::Error,
+    },
+    InvalidRegexTransform {
+        option: &'static str,
+        value: String,
+    },
+    InvalidStyleMap {
+        option: &'static str,
+        value: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::InvalidRegex {
+                option,
+                pattern,
+                error,
+            } => write!(
+                f,
+                "Invalid {}: {}. \
+                 The value must be a valid Rust regular expression. \
+                 See https://docs.rs/regex.\n{}",
+                option, pattern, error
+            ),
+            ConfigError::InvalidRegexTransform { option, value } => write!(
+                f,
+                "Invalid {}: {}. Expected PATTERN=REPLACEMENT.",
+                option, value
+            ),
+            ConfigError::InvalidStyleMap { option, value } => write!(
+                f,
+                "Invalid value for {}: {}. \
+                 Expected a comma-separated list of PATTERN=STYLE pairs.",
+                option, value
+            ),
+        }
+    }
+}
+
+/// The regular expressions given as options, compiled by [`Config::build_regexes`].
+pub struct ConfigRegexes {
+    pub commit: Regex,
+    pub tokenization: Regex,
+    pub redact_lines: Vec<Regex>,
+    pub file_path_transforms: Vec<(Regex, String)>,
+}
+
 impl Config {
+    /// Compile the regular expressions given as options, so that this happens once rather than
+    /// every time they are used.
+    pub fn build_regexes(opt: &cli::Opt) -> Result<ConfigRegexes, ConfigError> {
+        let build_regex = |option, pattern: &str| {
+            Regex::new(pattern).map_err(|error| ConfigError::InvalidRegex {
+                option,
+                pattern: pattern.to_string(),
+                error,
+            })
+        };
+        Ok(ConfigRegexes {
+            commit: build_regex("commit-regex", &opt.commit_regex)?,
+            tokenization: build_regex("word-diff-regex", &opt.tokenization_regex)?,
+            redact_lines: opt
+                .redact_lines
+                .iter()
+                .map(|pattern| build_regex("redact-lines", pattern))
+                .collect::<Result<_, _>>()?,
+            file_path_transforms: opt
+                .file_path_regex_transform
+                .iter()
+                .map(|transform| match transform.find('=') {
+                    Some(i) => build_regex("file-path-regex-transform", &transform[..i])
+                        .map(|regex| (regex, transform[i + 1..].to_string())),
+                    None => Err(ConfigError::InvalidRegexTransform {
+                        option: "file-path-regex-transform",
+                        value: transform.to_string(),
+                    }),
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
     pub fn get_style(&self, state: &State) -> &Style {
         match state {
             State::HunkMinus(_) => &self.minus_style,
@@ -137,10 +311,57 @@ impl Config {
             State::CommitMeta => &self.commit_style,
             State::FileMeta => &self.file_style,
             State::HunkHeader(_, _) => &self.hunk_header_style,
-            State::SubmoduleLog => &self.file_style,
+            State::SubmoduleLog => &self.submodule_style,
             _ => delta_unreachable("Unreachable code reached in get_style."),
         }
     }
+
+    /// Return the hunk-header-style for `path`, taking hunk-header-style-by-language overrides
+    /// into account.
+    pub fn get_hunk_header_style(&self, path: &str) -> &Style {
+        let extension = Path::new(path)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        extension
+            .and_then(|extension| self.hunk_header_style_by_language.get(&extension))
+            .or_else(|| self.hunk_header_style_by_language.get("*"))
+            .unwrap_or(&self.hunk_header_style)
+    }
+
+    /// Whether --syntax-disabled-for disables syntax highlighting for `path`.
+    pub fn is_syntax_disabled_for(&self, path: &str) -> bool {
+        let path = path.to_lowercase();
+        self.syntax_disabled_for
+            .iter()
+            .any(|extension| path.ends_with(&format!(".{}", extension)))
+    }
+
+    /// Return `path` as it should be displayed, after applying the file-path-regex-transforms.
+    pub fn display_file_path<'p>(&self, path: &'p str) -> Cow<'p, str> {
+        if path == "/dev/null" {
+            return Cow::from(path);
+        }
+        self.file_path_regex_transforms.iter().fold(
+            Cow::from(path),
+            |path, (regex, replacement)| {
+                if regex.is_match(&path) {
+                    Cow::from(regex.replace(&path, replacement.as_str()).into_owned())
+                } else {
+                    path
+                }
+            },
+        )
+    }
+
+    /// Return the file-style for `path`, taking file-style-map overrides into account.
+    pub fn get_file_style(&self, path: &str) -> &Style {
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+        self.file_style_map
+            .iter()
+            .find(|(regex, _)| regex.is_match(path) || regex.is_match(file_name))
+            .map(|(_, style)| style)
+            .unwrap_or(&self.file_style)
+    }
 }
 
 impl From<cli::Opt> for Config {
@@ -157,6 +378,31 @@ impl From<cli::Opt> for Config {
             plus_empty_line_marker_style,
             whitespace_error_style,
         ) = make_hunk_styles(&opt);
+        let (minus_empty_line_style_arg, plus_empty_line_style_arg) = if opt.invert_diff {
+            (&opt.plus_empty_line_style, &opt.minus_empty_line_style)
+        } else {
+            (&opt.minus_empty_line_style, &opt.plus_empty_line_style)
+        };
+        let minus_empty_line_style = make_empty_line_style(
+            minus_empty_line_style_arg,
+            minus_style,
+            opt.computed.true_color,
+        );
+        let plus_empty_line_style = make_empty_line_style(
+            plus_empty_line_style_arg,
+            plus_style,
+            opt.computed.true_color,
+        );
+        let file_added_line_style = make_file_line_style(
+            &opt.file_added_line_style,
+            plus_style,
+            opt.computed.true_color,
+        );
+        let file_deleted_line_style = make_file_line_style(
+            &opt.file_deleted_line_style,
+            minus_style,
+            opt.computed.true_color,
+        );
 
         let (
             commit_style,
@@ -165,6 +411,33 @@ impl From<cli::Opt> for Config {
             hunk_header_file_style,
             hunk_header_line_number_style,
         ) = make_commit_file_hunk_header_styles(&opt);
+        let binary_diff_style = if opt.binary_diff_style.is_empty() {
+            None
+        } else {
+            Some(
+                Style::from_str_with_handling_of_special_decoration_attributes(
+                    &opt.binary_diff_style,
+                    None,
+                    Some(&opt.file_decoration_style),
+                    opt.computed.true_color,
+                    false,
+                ),
+            )
+        };
+        let submodule_style = if opt.submodule_style.is_empty() {
+            file_style
+        } else {
+            Style::from_str_with_handling_of_special_decoration_attributes(
+                &opt.submodule_style,
+                None,
+                Some(&opt.file_decoration_style),
+                opt.computed.true_color,
+                false,
+            )
+        };
+        let file_style_map =
+            parse_file_style_map(&opt).unwrap_or_else(|error| fatal(error.to_string()));
+        let hunk_header_style_by_language = parse_hunk_header_style_by_language(&opt);
 
         let (
             line_numbers_minus_style,
@@ -173,37 +446,108 @@ impl From<cli::Opt> for Config {
             line_numbers_left_style,
             line_numbers_right_style,
         ) = make_line_number_styles(&opt);
+        let line_numbers_separator_style = if opt.line_numbers_separator_style.is_empty() {
+            line_numbers_right_style
+        } else {
+            Style::from_str(
+                &opt.line_numbers_separator_style,
+                None,
+                None,
+                opt.computed.true_color,
+                false,
+            )
+        };
+        let side_by_side_column_separator_style = make_optional_style(
+            &opt.side_by_side_column_separator_style,
+            opt.computed.true_color,
+        );
+        let separator_before_left_panel_style =
+            make_optional_style(&opt.separator_before_left_panel, opt.computed.true_color)
+                .or(side_by_side_column_separator_style);
+        let separator_between_panels_style =
+            make_optional_style(&opt.separator_between_panels, opt.computed.true_color)
+                .or(side_by_side_column_separator_style);
 
         let max_line_distance_for_naively_paired_lines =
             env::get_env_var("DELTA_EXPERIMENTAL_MAX_LINE_DISTANCE_FOR_NAIVELY_PAIRED_LINES")
                 .map(|s| s.parse::<f64>().unwrap_or(0.0))
                 .unwrap_or(0.0);
 
-        let commit_regex = Regex::new(&opt.commit_regex).unwrap_or_else(|_| {
-            fatal(format!(
-                "Invalid commit-regex: {}. \
-                 The value must be a valid Rust regular expression. \
-                 See https://docs.rs/regex.",
-                opt.commit_regex
-            ));
-        });
-
-        let tokenization_regex = Regex::new(&opt.tokenization_regex).unwrap_or_else(|_| {
-            fatal(format!(
-                "Invalid word-diff-regex: {}. \
-                 The value must be a valid Rust regular expression. \
-                 See https://docs.rs/regex.",
-                opt.tokenization_regex
-            ));
-        });
+        let regexes = Config::build_regexes(&opt).unwrap_or_else(|error| fatal(error.to_string()));
 
-        let inline_hint_style = Style::from_str(
+        let mut inline_hint_style = Style::from_str(
             &opt.inline_hint_style,
             None,
             None,
             opt.computed.true_color,
             false,
         );
+        if let Some(inline_hint_color) = &opt.inline_hint_color {
+            inline_hint_style.ansi_term_style.foreground = Some(
+                color::try_parse_color(inline_hint_color, opt.computed.true_color).unwrap_or_else(
+                    || {
+                        fatal(format!(
+                            "Invalid color for --inline-hint-color: {}. \
+                             Use --inline-hint-style to set a full style string.",
+                            inline_hint_color
+                        ))
+                    },
+                ),
+            );
+        }
+        let line_range_style = Style::from_str(
+            &opt.line_range_style,
+            None,
+            None,
+            opt.computed.true_color,
+            false,
+        );
+        let column_shades = if opt.syntax_wrap_color_columns > 0 {
+            Some((
+                opt.syntax_wrap_color_columns,
+                Style::from_str(
+                    &opt.column_shade_odd,
+                    None,
+                    None,
+                    opt.computed.true_color,
+                    false,
+                ),
+                Style::from_str(
+                    &opt.column_shade_even,
+                    None,
+                    None,
+                    opt.computed.true_color,
+                    false,
+                ),
+            ))
+        } else {
+            None
+        };
+        let redact_style = Style::from_str(
+            &opt.redact_style,
+            None,
+            None,
+            opt.computed.true_color,
+            false,
+        );
+        let line_ending_style = Style::from_str(
+            &opt.line_ending_style,
+            None,
+            None,
+            opt.computed.true_color,
+            false,
+        );
+        let show_line_endings_on = match opt.show_line_endings_on.as_str() {
+            "minus" => LineEndingsOn::Minus,
+            "plus" => LineEndingsOn::Plus,
+            "context" => LineEndingsOn::Context,
+            "all" => LineEndingsOn::All,
+            _ => fatal(format!(
+                "Invalid value for show-line-endings-on: {}. \
+                 Valid values are \"minus\", \"plus\", \"context\", and \"all\".",
+                opt.show_line_endings_on
+            )),
+        };
         let git_minus_style = match opt.git_config_entries.get("color.diff.old") {
             Some(GitConfigEntry::Style(s)) => Style::from_git_str(s),
             _ => *style::GIT_DEFAULT_MINUS_STYLE,
@@ -224,18 +568,72 @@ impl From<cli::Opt> for Config {
             // Note that "default" is not documented
             Some("ansi") | Some("default") | None => BgFillMethod::TryAnsiSequence,
             Some("spaces") => BgFillMethod::Spaces,
-            _ => fatal("Invalid option for line-fill-method: Expected \"ansi\" or \"spaces\"."),
+            Some(method) if method.starts_with("pattern:") => {
+                BgFillMethod::Pattern(parse_line_fill_pattern(&method["pattern:".len()..]))
+            }
+            _ => fatal(
+                "Invalid option for line-fill-method: Expected \"ansi\", \"spaces\" or \"pattern:<char>\".",
+            ),
         };
 
         let side_by_side_data = side_by_side::SideBySideData::new_sbs(
             &opt.computed.decorations_width,
             &opt.computed.available_terminal_width,
         );
-        let side_by_side_data = ansifill::UseFullPanelWidth::sbs_odd_fix(
+        let side_by_side =
+            opt.side_by_side && !side_by_side_data.panels_too_narrow(opt.min_panel_width);
+        let hunk_header_context_position = match opt.hunk_header_context_position.as_str() {
+            "left" => HunkHeaderContextPosition::Left,
+            "right" => HunkHeaderContextPosition::Right,
+            _ => fatal(format!(
+                "Invalid value for hunk-header-context-position: {}. \
+                 Valid values are \"left\" and \"right\".",
+                opt.hunk_header_context_position
+            )),
+        };
+        let hunk_header_context_style = if opt.hunk_header_context_style.is_empty() {
+            None
+        } else {
+            Some(Style::from_str(
+                &opt.hunk_header_context_style,
+                None,
+                None,
+                opt.computed.true_color,
+                false,
+            ))
+        };
+
+        let output_format = match opt.output_format.as_str() {
+            "delta" => OutputFormat::Delta,
+            "context-diff" => OutputFormat::ContextDiff,
+            _ => fatal(format!(
+                "Invalid value for output-format: {}. \
+                 Valid values are \"delta\" and \"context-diff\".",
+                opt.output_format
+            )),
+        };
+
+        // With --invert-diff the removed lines are displayed in the panel for added lines.
+        let side_by_side_direction = match (opt.side_by_side_direction.as_str(), opt.invert_diff) {
+            ("ltr", false) | ("rtl", true) => SideBySideDirection::LeftToRight,
+            ("rtl", false) | ("ltr", true) => SideBySideDirection::RightToLeft,
+            _ => fatal(format!(
+                "Invalid value for side-by-side-direction: {}. \
+                 Valid values are \"ltr\" and \"rtl\".",
+                opt.side_by_side_direction
+            )),
+        };
+        let mut side_by_side_data = ansifill::UseFullPanelWidth::sbs_odd_fix(
             &opt.computed.decorations_width,
             &line_fill_method,
+            side_by_side_direction,
             side_by_side_data,
         );
+        // The separator between the panels takes its width from the rightmost panel.
+        let rightmost_panel = side_by_side_direction.panels()[1];
+        side_by_side_data[rightmost_panel].width = side_by_side_data[rightmost_panel]
+            .width
+            .saturating_sub(ansi::measure_text_width(&opt.line_numbers_separator_format));
 
         let navigate_regexp = if opt.navigate || opt.show_themes {
             Some(navigate::make_navigate_regexp(
@@ -251,36 +649,98 @@ impl From<cli::Opt> for Config {
         };
 
         let wrap_max_lines_plus1 = adapt_wrap_max_lines_argument(opt.wrap_max_lines);
+        let context_after = parse_context_argument(&opt.context_after, "context-after");
+        let context_before = parse_context_argument(&opt.context_before, "context-before");
+        let syntax_disabled_for = parse_syntax_disabled_for(&opt);
+        let commit_timestamp_format = parse_commit_timestamp_format(&opt.commit_timestamp_format);
+        let mut git_config_parameters = parse_git_config_parameters(&opt.git_config_parameters);
+        if let Some(diff_algorithm) = &opt.diff_algorithm {
+            git_config_parameters.push(parse_diff_algorithm(diff_algorithm));
+        }
 
         Self {
             available_terminal_width: opt.computed.available_terminal_width,
             background_color_extends_to_terminal_width: opt
                 .computed
                 .background_color_extends_to_terminal_width,
+            binary_diff_style,
+            column_shades,
             commit_style,
+            check_contrast: opt.check_contrast,
             color_only: opt.color_only,
-            commit_regex,
+            context_after,
+            context_before,
+            commit_regex: regexes.commit,
+            commit_timestamp_format,
+            commit_timestamp_timezone: match opt.commit_timestamp_timezone.as_str() {
+                "original" => CommitTimestampTimezone::Original,
+                "local" => CommitTimestampTimezone::Local,
+                "utc" => CommitTimestampTimezone::Utc,
+                _ => fatal(format!(
+                    "Invalid value for commit-timestamp-timezone: {}. \
+                     Valid values are \"original\", \"local\" and \"utc\".",
+                    opt.commit_timestamp_timezone
+                )),
+            },
+            copy_mode: opt.copy_mode,
+            copy_mode_format: match opt.copy_mode_format.as_str() {
+                "diff" => CopyModeFormat::Diff,
+                "markdown" => CopyModeFormat::Markdown,
+                "plain" => CopyModeFormat::Plain,
+                _ => fatal(format!(
+                    "Invalid value for copy-mode-format: {}. \
+                     Valid values are \"diff\", \"markdown\" and \"plain\".",
+                    opt.copy_mode_format
+                )),
+            },
             cwd_relative_to_repo_root: std::env::var("GIT_PREFIX").ok(),
             decorations_width: opt.computed.decorations_width,
             default_language: opt.default_language,
             diff_stat_align_width: opt.diff_stat_align_width,
+            dry_run: opt.dry_run,
             error_exit_code: 2, // Use 2 for error because diff uses 0 and 1 for non-error.
             file_added_label,
+            file_added_line_style,
             file_copied_label,
+            file_deleted_line_style,
             file_modified_label,
             file_removed_label,
             file_renamed_label,
             hunk_label,
+            file_stat_bar_style: match opt.file_stat_bar_style.as_str() {
+                "git" => DiffStatBarStyle::Git,
+                "plus-minus" => DiffStatBarStyle::PlusMinus,
+                "line" => DiffStatBarStyle::Line,
+                "shade" => DiffStatBarStyle::Shade,
+                _ => fatal(format!(
+                    "Invalid value for file-stat-bar-style: {}. \
+                     Valid values are \"git\", \"plus-minus\", \"line\" and \"shade\".",
+                    opt.file_stat_bar_style
+                )),
+            },
+            file_stat_bar_width: opt.file_stat_bar_width,
+            file_stat_width: opt.file_stat_width,
             file_style,
+            file_style_map,
+            file_path_regex_transforms: regexes.file_path_transforms,
             git_config: opt.git_config,
             git_config_entries: opt.git_config_entries,
+            git_config_parameters,
+            github_actions_annotations: opt.github_actions_annotations,
+            hunk_header_context_max_length: opt.hunk_header_context_max_length,
+            hunk_header_context_position,
+            hunk_header_context_style,
+            hunk_header_file_path_format: opt.hunk_header_file_path_format,
             hunk_header_file_style,
             hunk_header_line_number_style,
+            hunk_header_marker_style: make_optional_style(
+                &opt.hunk_header_marker_style,
+                opt.computed.true_color,
+            ),
             hunk_header_style,
-            hunk_header_style_include_file_path: opt
-                .hunk_header_style
-                .split(' ')
-                .any(|s| s == "file"),
+            hunk_header_style_by_language,
+            hunk_header_style_include_file_path: opt.hunk_header_file_path
+                || opt.hunk_header_style.split(' ').any(|s| s == "file"),
             hunk_header_style_include_line_number: opt
                 .hunk_header_style
                 .split(' ')
@@ -290,8 +750,12 @@ impl From<cli::Opt> for Config {
             hyperlinks_file_link_format: opt.hyperlinks_file_link_format,
             inspect_raw_lines: opt.computed.inspect_raw_lines,
             inline_hint_style,
+            invert_diff: opt.invert_diff,
             keep_plus_minus_markers: opt.keep_plus_minus_markers,
-            line_fill_method: if !opt.computed.stdout_is_term && !TESTING {
+            line_fill_method: if line_fill_method == BgFillMethod::TryAnsiSequence
+                && !opt.computed.stdout_is_term
+                && !TESTING
+            {
                 // Don't write ANSI sequences (which rely on the width of the
                 // current terminal) into a file. Also see UseFullPanelWidth.
                 // But when testing always use given value.
@@ -304,6 +768,13 @@ impl From<cli::Opt> for Config {
                 opt.line_numbers_left_format,
                 opt.line_numbers_right_format,
             ),
+            line_numbers_format_minusplus: MinusPlus::new(
+                Some(opt.line_numbers_minus_format).filter(|format| !format.is_empty()),
+                Some(opt.line_numbers_plus_format).filter(|format| !format.is_empty()),
+            ),
+            line_numbers_relative_to_hunk: opt.line_numbers_relative_to_hunk,
+            line_numbers_separator_format: opt.line_numbers_separator_format,
+            line_numbers_separator_style,
             line_numbers_style_leftright: LeftRight::new(
                 line_numbers_left_style,
                 line_numbers_right_style,
@@ -313,10 +784,12 @@ impl From<cli::Opt> for Config {
                 line_numbers_plus_style,
             ),
             line_numbers_zero_style,
+            line_range_style,
             line_buffer_size: opt.line_buffer_size,
+            line_ending_style,
             max_line_distance: opt.max_line_distance,
             max_line_distance_for_naively_paired_lines,
-            max_line_length: match (opt.side_by_side, wrap_max_lines_plus1) {
+            max_line_length: match (side_by_side, wrap_max_lines_plus1) {
                 (false, _) | (true, 1) => opt.max_line_length,
                 // Ensure there is enough text to wrap, either don't truncate the input at all (0)
                 // or ensure there is enough for the requested number of lines.
@@ -335,6 +808,7 @@ impl From<cli::Opt> for Config {
             },
             minus_emph_style,
             minus_empty_line_marker_style,
+            minus_empty_line_style,
             minus_file: opt.minus_file,
             minus_non_emph_style,
             minus_style,
@@ -342,24 +816,43 @@ impl From<cli::Opt> for Config {
             navigate_regexp,
             null_style: Style::new(),
             null_syntect_style: SyntectStyle::default(),
+            output_buffer_size: opt.output_buffer_size,
+            output_format,
             pager: opt.pager,
             paging_mode: opt.computed.paging_mode,
             plus_emph_style,
             plus_empty_line_marker_style,
+            plus_empty_line_style,
             plus_file: opt.plus_file,
             plus_non_emph_style,
             plus_style,
             git_minus_style,
             git_plus_style,
+            redact_lines: regexes.redact_lines,
+            redact_placeholder: opt.redact_placeholder,
+            redact_style,
             relative_paths: opt.relative_paths,
+            show_line_endings: opt.show_line_endings,
+            show_line_endings_on,
             show_themes: opt.show_themes,
-            side_by_side: opt.side_by_side,
+            separator_before_left_panel_style,
+            separator_between_panels_style,
+            side_by_side,
+            side_by_side_column_separator_style,
             side_by_side_data,
+            side_by_side_direction,
             syntax_dummy_theme: SyntaxTheme::default(),
+            submodule_style,
+            suppress_diff_binary_notice: opt.suppress_diff_binary_notice,
+            suppress_empty_hunk_context: opt.suppress_empty_hunk_context,
+            syntax_disabled_for,
+            syntax_ignore_case: opt.syntax_ignore_case,
+            syntax_line_range: parse_syntax_line_range(&opt.syntax_line_range),
+            syntax_padding: opt.syntax_padding,
             syntax_set: opt.computed.syntax_set,
             syntax_theme: opt.computed.syntax_theme,
             tab_width: opt.tab_width,
-            tokenization_regex,
+            tokenization_regex: regexes.tokenization,
             true_color: opt.computed.true_color,
             truncation_symbol: format!("{}→{}", ansi::ANSI_SGR_REVERSE, ansi::ANSI_SGR_RESET),
             wrap_config: WrapConfig {
@@ -369,6 +862,13 @@ impl From<cli::Opt> for Config {
                     "wrap-right-prefix-symbol",
                     opt.wrap_right_prefix_symbol,
                 ),
+                overflow_symbol: if opt.wrap_overflow_symbol.is_empty() {
+                    None
+                } else {
+                    let symbol =
+                        ensure_display_width_1("wrap-overflow-symbol", opt.wrap_overflow_symbol);
+                    Some(inline_hint_style.paint(symbol).to_string())
+                },
                 use_wrap_right_permille: {
                     let arg = &opt.wrap_right_percent;
                     let percent = remove_percent_suffix(arg)
@@ -379,13 +879,26 @@ impl From<cli::Opt> for Config {
                                 &arg, err
                             ))
                         });
-                    if percent.is_finite() && percent > 0.0 && percent < 100.0 {
+                    if percent.is_finite() && (0.0..=100.0).contains(&percent) {
                         (percent * 10.0).round() as usize
                     } else {
-                        fatal("Invalid value for wrap-right-percent, not between 0 and 100.")
+                        fatal(format!(
+                            "--wrap-right-percent must be between 0% and 100%, got {}%",
+                            remove_percent_suffix(arg)
+                        ))
                     }
                 },
                 max_lines: wrap_max_lines_plus1,
+                newline_behavior: match opt.wrap_indicator_newline_behavior.as_str() {
+                    "absorb" => WrapNewlineBehavior::Absorb,
+                    "visible" => WrapNewlineBehavior::Visible,
+                    "strip" => WrapNewlineBehavior::Strip,
+                    _ => fatal(format!(
+                        "Invalid value for wrap-indicator-newline-behavior: {}. \
+                         Valid values are \"absorb\", \"visible\" and \"strip\".",
+                        opt.wrap_indicator_newline_behavior
+                    )),
+                },
                 inline_hint_syntect_style: SyntectStyle::from_delta_style(inline_hint_style),
             },
             whitespace_error_style,
@@ -394,6 +907,193 @@ impl From<cli::Opt> for Config {
     }
 }
 
+/// Split a comma-separated list of KEY=VALUE git config settings into
+/// its elements, each of which can be passed to git as `-c KEY=VALUE`.
+fn parse_git_config_parameters(arg: &str) -> Vec<String> {
+    arg.split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| match param.find('=') {
+            Some(i) if !param[..i].trim().is_empty() => param.to_string(),
+            _ => fatal(format!(
+                "Invalid value for git-config-parameters: {}. \
+                 Expected a comma-separated list of KEY=VALUE pairs.",
+                param
+            )),
+        })
+        .collect()
+}
+
+/// Parse the START-END (or single line number) argument of syntax-line-range.
+fn parse_syntax_line_range(arg: &Option<String>) -> Option<(usize, usize)> {
+    let arg = arg.as_deref()?;
+    let (start, end) = match arg.find('-') {
+        Some(i) => (&arg[..i], &arg[i + 1..]),
+        None => (arg, arg),
+    };
+    match (start.trim().parse::<usize>(), end.trim().parse::<usize>()) {
+        (Ok(start), Ok(end)) if start <= end => Some((start, end)),
+        _ => fatal(format!(
+            "Invalid value for syntax-line-range: {}. \
+             Expected START-END, where START is not greater than END.",
+            arg
+        )),
+    }
+}
+
+/// Return the git config parameter selecting the diff-algorithm.
+fn parse_diff_algorithm(algorithm: &str) -> String {
+    match algorithm {
+        "myers" | "minimal" | "patience" | "histogram" => format!("diff.algorithm={}", algorithm),
+        _ => fatal(format!(
+            "Invalid value for diff-algorithm: {}. \
+             Valid values are \"myers\", \"minimal\", \"patience\" and \"histogram\".",
+            algorithm
+        )),
+    }
+}
+
+/// Parse the comma-separated EXTENSION=STYLE list of hunk-header-style-by-language. Extensions are
+/// stored in lower case and without a leading '.'.
+fn parse_hunk_header_style_by_language(opt: &cli::Opt) -> HashMap<String, Style> {
+    opt.hunk_header_style_by_language
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (extension, style) = match entry.find('=') {
+                Some(i) if !entry[..i].trim().is_empty() => (entry[..i].trim(), &entry[i + 1..]),
+                _ => fatal(format!(
+                    "Invalid value for hunk-header-style-by-language: {}. \
+                     Expected a comma-separated list of EXTENSION=STYLE pairs.",
+                    entry
+                )),
+            };
+            let style = Style::from_str_with_handling_of_special_decoration_attributes(
+                style,
+                None,
+                Some(&opt.hunk_header_decoration_style),
+                opt.computed.true_color,
+                false,
+            );
+            (extension.trim_start_matches('.').to_lowercase(), style)
+        })
+        .collect()
+}
+
+/// Check that the strftime-style format string of commit-timestamp-format is valid: chrono
+/// panics when formatting a date with an invalid format string.
+fn parse_commit_timestamp_format(format: &Option<String>) -> Option<String> {
+    let format = format.as_deref()?;
+    if StrftimeItems::new(format).any(|item| item == Item::Error) {
+        fatal(format!(
+            "Invalid value for commit-timestamp-format: {}.",
+            format
+        ));
+    }
+    Some(format.to_string())
+}
+
+/// Parse the comma-separated extensions of syntax-disabled-for, adding the defaults if
+/// syntax-disabled-for-default is set. Extensions are stored in lower case and without a leading
+/// '.'.
+fn parse_syntax_disabled_for(opt: &cli::Opt) -> HashSet<String> {
+    let defaults: &[&str] = if opt.syntax_disabled_for_default {
+        DEFAULT_SYNTAX_DISABLED_FOR
+    } else {
+        &[]
+    };
+    opt.syntax_disabled_for
+        .split(',')
+        .chain(defaults.iter().copied())
+        .map(|extension| extension.trim().trim_start_matches('.').to_lowercase())
+        .filter(|extension| !extension.is_empty())
+        .collect()
+}
+
+/// Parse the comma-separated PATTERN=STYLE list of file-style-map. Each PATTERN is converted to
+/// a case-insensitive regex anchored at both ends.
+fn parse_file_style_map(opt: &cli::Opt) -> Result<Vec<(Regex, Style)>, ConfigError> {
+    opt.file_style_map
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (pattern, style) = match entry.find('=') {
+                Some(i) if !entry[..i].trim().is_empty() => (entry[..i].trim(), &entry[i + 1..]),
+                _ => {
+                    return Err(ConfigError::InvalidStyleMap {
+                        option: "file-style-map",
+                        value: entry.to_string(),
+                    })
+                }
+            };
+            let pattern = if pattern.contains(&['*', '?'][..]) {
+                pattern.to_string()
+            } else {
+                format!("*.{}", pattern.trim_start_matches('.'))
+            };
+            let regex = pattern
+                .split('*')
+                .map(|part| {
+                    part.split('?')
+                        .map(regex::escape)
+                        .collect::<Vec<_>>()
+                        .join("[^/]")
+                })
+                .collect::<Vec<_>>()
+                .join("[^/]*");
+            let style = Style::from_str_with_handling_of_special_decoration_attributes(
+                style,
+                None,
+                Some(&opt.file_decoration_style),
+                opt.computed.true_color,
+                false,
+            );
+            Regex::new(&format!("(?i)^{}$", regex))
+                .map(|regex| (regex, style))
+                .map_err(|error| ConfigError::InvalidRegex {
+                    option: "file-style-map",
+                    pattern: entry.to_string(),
+                    error,
+                })
+        })
+        .collect()
+}
+
+/// Parse the value of --minus-color or --plus-color, which must be a single color.
+fn parse_background_color_arg(
+    color: &str,
+    element_name: &str,
+    true_color: bool,
+) -> Option<ansi_term::Color> {
+    color::try_parse_color(color, true_color).unwrap_or_else(|| {
+        fatal(format!(
+            "Invalid color for --{name}-color: {color}. \
+             Use --{name}-style to set a full style string, \
+             or --{name}-color for background color only.",
+            name = element_name,
+            color = color,
+        ))
+    })
+}
+
+/// Parse --minus-empty-line-style or --plus-empty-line-style. None means that empty lines are
+/// displayed in `base_style`, like all other lines.
+fn make_empty_line_style(style_string: &str, base_style: Style, true_color: bool) -> Option<Style> {
+    if style_string.is_empty() {
+        None
+    } else {
+        Some(Style::from_str(
+            style_string,
+            Some(base_style),
+            None,
+            true_color,
+            false,
+        ))
+    }
+}
+
 fn make_hunk_styles(
     opt: &cli::Opt,
 ) -> (
@@ -410,7 +1110,7 @@ fn make_hunk_styles(
 ) {
     let is_light_mode = opt.computed.is_light_mode;
     let true_color = opt.computed.true_color;
-    let minus_style = Style::from_str(
+    let mut minus_style = Style::from_str(
         &opt.minus_style,
         Some(Style::from_colors(
             None,
@@ -423,6 +1123,10 @@ fn make_hunk_styles(
         true_color,
         false,
     );
+    if let Some(minus_color) = &opt.minus_color {
+        minus_style.ansi_term_style.background =
+            parse_background_color_arg(minus_color, "minus", true_color);
+    }
 
     let minus_emph_style = Style::from_str(
         &opt.minus_emph_style,
@@ -464,7 +1168,7 @@ fn make_hunk_styles(
 
     let zero_style = Style::from_str(&opt.zero_style, None, None, true_color, false);
 
-    let plus_style = Style::from_str(
+    let mut plus_style = Style::from_str(
         &opt.plus_style,
         Some(Style::from_colors(
             None,
@@ -477,6 +1181,10 @@ fn make_hunk_styles(
         true_color,
         false,
     );
+    if let Some(plus_color) = &opt.plus_color {
+        plus_style.ansi_term_style.background =
+            parse_background_color_arg(plus_color, "plus", true_color);
+    }
 
     let plus_emph_style = Style::from_str(
         &opt.plus_emph_style,
@@ -519,6 +1227,21 @@ fn make_hunk_styles(
     let whitespace_error_style =
         Style::from_str(&opt.whitespace_error_style, None, None, true_color, false);
 
+    if opt.invert_diff {
+        return (
+            plus_style,
+            plus_emph_style,
+            plus_non_emph_style,
+            plus_empty_line_marker_style,
+            zero_style,
+            minus_style,
+            minus_emph_style,
+            minus_non_emph_style,
+            minus_empty_line_marker_style,
+            whitespace_error_style,
+        );
+    }
+
     (
         minus_style,
         minus_emph_style,
@@ -533,6 +1256,30 @@ fn make_hunk_styles(
     )
 }
 
+/// The style replacing minus-style or plus-style (`default`) for all lines of a deleted or added
+/// file, if any.
+fn make_file_line_style(style_string: &str, default: Style, true_color: bool) -> Option<Style> {
+    if style_string.is_empty() {
+        None
+    } else {
+        Some(Style::from_str(
+            style_string,
+            Some(default),
+            None,
+            true_color,
+            false,
+        ))
+    }
+}
+
+fn make_optional_style(style_string: &str, true_color: bool) -> Option<Style> {
+    if style_string.is_empty() {
+        None
+    } else {
+        Some(Style::from_str(style_string, None, None, true_color, false))
+    }
+}
+
 fn make_line_number_styles(opt: &cli::Opt) -> (Style, Style, Style, Style, Style) {
     let true_color = opt.computed.true_color;
     let line_numbers_left_style =
@@ -622,11 +1369,43 @@ pub const HEADER_LEN: usize = 7;
 
 #[cfg(test)]
 pub mod tests {
+    use super::{parse_file_style_map, Config, ConfigError};
     use crate::bat_utils::output::PagingMode;
     use crate::cli;
     use crate::tests::integration_test_utils;
     use std::fs::remove_file;
 
+    #[test]
+    fn test_wrap_max_lines_unlimited() {
+        for arg in &["unlimited", "none", "∞", "inf"] {
+            let config = integration_test_utils::make_config_from_args(&["--wrap-max-lines", arg]);
+            assert_eq!(config.wrap_config.max_lines, 0);
+        }
+        let config = integration_test_utils::make_config_from_args(&["--wrap-max-lines", "0"]);
+        assert_eq!(config.wrap_config.max_lines, 1);
+    }
+
+    #[test]
+    fn test_wrap_right_percent() {
+        for (arg, permille) in &[("0", 0), ("0%", 0), ("37.5%", 375), ("100", 1000)] {
+            let config =
+                integration_test_utils::make_config_from_args(&["--wrap-right-percent", arg]);
+            assert_eq!(config.wrap_config.use_wrap_right_permille, *permille);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "--wrap-right-percent must be between 0% and 100%, got 150.0%")]
+    fn test_wrap_right_percent_above_100() {
+        integration_test_utils::make_config_from_args(&["--wrap-right-percent", "150.0%"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "--wrap-right-percent must be between 0% and 100%, got -0.1%")]
+    fn test_wrap_right_percent_below_0() {
+        integration_test_utils::make_config_from_args(&["--wrap-right-percent=-0.1"]);
+    }
+
     #[test]
     fn test_get_computed_values_from_config() {
         let git_config_contents = b"
@@ -652,4 +1431,207 @@ pub mod tests {
         // syntax_set doesn't depend on gitconfig.
         remove_file(git_config_path).unwrap();
     }
+
+    #[test]
+    fn test_git_config_parameters() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--git-config-parameters",
+            "diff.renames=true, diff.algorithm=patience,",
+        ]);
+        assert_eq!(
+            config.git_config_parameters,
+            vec!["diff.renames=true", "diff.algorithm=patience"]
+        );
+        let config = integration_test_utils::make_config_from_args(&[]);
+        assert!(config.git_config_parameters.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for git-config-parameters: diff.renames")]
+    fn test_git_config_parameters_without_value() {
+        integration_test_utils::make_config_from_args(&["--git-config-parameters", "diff.renames"]);
+    }
+
+    #[test]
+    fn test_diff_algorithm() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--git-config-parameters",
+            "diff.renames=true",
+            "--diff-algorithm",
+            "histogram",
+        ]);
+        assert_eq!(
+            config.git_config_parameters,
+            vec!["diff.renames=true", "diff.algorithm=histogram"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for diff-algorithm: fast")]
+    fn test_diff_algorithm_invalid() {
+        integration_test_utils::make_config_from_args(&["--diff-algorithm", "fast"]);
+    }
+
+    #[test]
+    fn test_file_style_map() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--file-style",
+            "blue",
+            "--file-style-map",
+            "*.test.rs=dim, RS=bold red,src/*/mod.rs=green",
+        ]);
+        let style = |path| config.get_file_style(path).ansi_term_style;
+        assert_eq!(style("src/a.test.rs"), ansi_term::Style::new().dimmed());
+        assert_eq!(style("src/a.rs"), ansi_term::Color::Red.bold());
+        assert_eq!(style("SRC/A.Rs"), ansi_term::Color::Red.bold());
+        assert_eq!(style("src/features/mod.rs"), ansi_term::Color::Red.bold());
+        assert_eq!(style("src/a.rsx"), ansi_term::Color::Blue.normal());
+        assert_eq!(style("src/a.py"), ansi_term::Color::Blue.normal());
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--file-style",
+            "blue",
+            "--file-style-map",
+            "src/*/mod.rs=green,rs=red",
+        ]);
+        let style = |path| config.get_file_style(path).ansi_term_style;
+        assert_eq!(
+            style("src/features/mod.rs"),
+            ansi_term::Color::Green.normal()
+        );
+        assert_eq!(style("src/a/b/mod.rs"), ansi_term::Color::Red.normal());
+
+        let opt = integration_test_utils::make_options_from_args(&["--file-style-map", "rs"]);
+        match parse_file_style_map(&opt) {
+            Err(error @ ConfigError::InvalidStyleMap { .. }) => {
+                assert!(error
+                    .to_string()
+                    .starts_with("Invalid value for file-style-map: rs."));
+            }
+            _ => panic!("expected an invalid style map error"),
+        }
+    }
+
+    #[test]
+    fn test_minus_plus_color_set_background_only() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--minus-style",
+            "bold red",
+            "--minus-color",
+            "blue",
+            "--plus-style",
+            "syntax italic",
+            "--plus-color",
+            "#00ff00",
+            "--true-color",
+            "always",
+        ]);
+        assert_eq!(
+            config.minus_style.ansi_term_style,
+            ansi_term::Color::Red.on(ansi_term::Color::Blue).bold()
+        );
+        assert!(config.plus_style.is_syntax_highlighted);
+        assert!(config.plus_style.ansi_term_style.is_italic);
+        assert_eq!(
+            config.plus_style.ansi_term_style.background,
+            Some(ansi_term::Color::RGB(0, 255, 0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Use --plus-style to set a full style string")]
+    fn test_plus_color_invalid() {
+        integration_test_utils::make_config_from_args(&["--plus-color", "bold"]);
+    }
+
+    #[test]
+    fn test_inline_hint_color() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--inline-hint-style",
+            "bold red",
+            "--inline-hint-color",
+            "green",
+        ]);
+        assert_eq!(
+            config.inline_hint_style.ansi_term_style,
+            ansi_term::Color::Green.bold()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Use --inline-hint-style to set a full style string")]
+    fn test_inline_hint_color_invalid() {
+        integration_test_utils::make_config_from_args(&["--inline-hint-color", "bold"]);
+    }
+
+    #[test]
+    fn test_build_regexes() {
+        let opt = integration_test_utils::make_options_from_args(&["--word-diff-regex", "[a-z"]);
+        match Config::build_regexes(&opt) {
+            Err(error @ ConfigError::InvalidRegex { .. }) => {
+                assert!(error
+                    .to_string()
+                    .starts_with("Invalid word-diff-regex: [a-z. The value must be"));
+            }
+            _ => panic!("expected an invalid regex error"),
+        }
+
+        let opt = integration_test_utils::make_options_from_args(&["--commit-regex", "^Commit:"]);
+        let commit_regex = Config::build_regexes(&opt).unwrap().commit;
+        assert!(commit_regex.is_match("Commit: abc"));
+
+        let opt = integration_test_utils::make_options_from_args(&[
+            "--redact-lines",
+            "password",
+            "--redact-lines",
+            "[a-z",
+        ]);
+        match Config::build_regexes(&opt) {
+            Err(error @ ConfigError::InvalidRegex { .. }) => {
+                assert!(error.to_string().starts_with("Invalid redact-lines: [a-z."));
+            }
+            _ => panic!("expected an invalid regex error"),
+        }
+
+        let opt = integration_test_utils::make_options_from_args(&[
+            "--file-path-regex-transform",
+            "^services/",
+        ]);
+        match Config::build_regexes(&opt) {
+            Err(error @ ConfigError::InvalidRegexTransform { .. }) => {
+                assert!(error
+                    .to_string()
+                    .starts_with("Invalid file-path-regex-transform: ^services/."));
+            }
+            _ => panic!("expected an invalid regex transform error"),
+        }
+    }
+
+    #[test]
+    fn test_output_buffer_size() {
+        let config = integration_test_utils::make_config_from_args(&[]);
+        assert_eq!(config.output_buffer_size, 0);
+        let config = integration_test_utils::make_config_from_args(&["--output-buffer-size", "64"]);
+        assert_eq!(config.output_buffer_size, 64);
+    }
+
+    #[test]
+    fn test_syntax_disabled_for() {
+        let config = integration_test_utils::make_config_from_args(&[]);
+        assert!(!config.is_syntax_disabled_for("src/a.json"));
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--syntax-disabled-for",
+            "JSON, .min.js",
+        ]);
+        assert!(config.is_syntax_disabled_for("src/a.json"));
+        assert!(config.is_syntax_disabled_for("static/app.MIN.js"));
+        assert!(!config.is_syntax_disabled_for("static/app.js"));
+        assert!(!config.is_syntax_disabled_for("Cargo.lock"));
+
+        let config =
+            integration_test_utils::make_config_from_args(&["--syntax-disabled-for-default"]);
+        assert!(config.is_syntax_disabled_for("Cargo.lock"));
+        assert!(!config.is_syntax_disabled_for("src/main.rs"));
+    }
 }
@@ -0,0 +1,183 @@
+use std::io::Write;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::config::Config;
+use crate::delta::State;
+use crate::features::line_numbers::LineNumbersData;
+use crate::features::side_by_side::{self, Left, Right};
+use crate::style::Style;
+
+/// Write a description of how delta handled input line `line_number` to `writer`, instead of the
+/// line itself. Used by --dry-run. Each line is described by a single JSON object, so that the
+/// output can be processed line-by-line.
+pub fn emit_line_info(
+    writer: &mut dyn Write,
+    line_number: usize,
+    line: &str,
+    state: &State,
+    line_numbers_data: &Option<LineNumbersData>,
+    config: &Config,
+) -> std::io::Result<()> {
+    let style = match get_style(state, config) {
+        Some(style) => json_string(&style.to_string()),
+        None => "null".to_string(),
+    };
+    let (panel, wrap) = if config.side_by_side {
+        match get_panel(state, config) {
+            Some(panel) => (
+                json_string(panel),
+                json_string(get_wrap(line, state, line_numbers_data, config)),
+            ),
+            None => ("null".to_string(), "null".to_string()),
+        }
+    } else {
+        ("null".to_string(), "null".to_string())
+    };
+    writeln!(
+        writer,
+        "{{\"line\": {}, \"state\": {}, \"style\": {}, \"wrap\": {}, \"panel\": {}, \"text\": {}}}",
+        line_number,
+        json_string(state_name(state)),
+        style,
+        wrap,
+        panel,
+        json_string(line),
+    )
+}
+
+fn state_name(state: &State) -> &'static str {
+    match state {
+        State::CommitMeta => "commit-meta",
+        State::FileMeta => "file-meta",
+        State::HunkHeader(_, _) => "hunk-header",
+        State::HunkZero | State::HunkZeroWrapped => "hunk-zero",
+        State::HunkMinus(_) | State::HunkMinusWrapped => "hunk-minus",
+        State::HunkPlus(_) | State::HunkPlusWrapped => "hunk-plus",
+        State::SubmoduleLog => "submodule-log",
+        State::SubmoduleShort(_) => "submodule-short",
+        State::Unknown => "unknown",
+    }
+}
+
+fn get_style<'a>(state: &State, config: &'a Config) -> Option<&'a Style> {
+    match state {
+        State::HunkZero | State::HunkZeroWrapped => Some(&config.zero_style),
+        State::HunkMinusWrapped => Some(&config.minus_style),
+        State::HunkPlusWrapped => Some(&config.plus_style),
+        State::SubmoduleShort(_) | State::Unknown => None,
+        _ => Some(config.get_style(state)),
+    }
+}
+
+/// The side-by-side panel(s) a hunk line is displayed in.
+fn get_panel(state: &State, config: &Config) -> Option<&'static str> {
+    let side = match state {
+        State::HunkZero | State::HunkZeroWrapped => return Some("both"),
+        State::HunkMinus(_) | State::HunkMinusWrapped => Left,
+        State::HunkPlus(_) | State::HunkPlusWrapped => Right,
+        _ => return None,
+    };
+    // The direction also accounts for --invert-diff swapping the panels.
+    if config.side_by_side_direction.is_leftmost(side) {
+        Some("left")
+    } else {
+        Some("right")
+    }
+}
+
+/// Whether a side-by-side hunk line fits into its panel, or would be wrapped or truncated.
+fn get_wrap(
+    line: &str,
+    state: &State,
+    line_numbers_data: &Option<LineNumbersData>,
+    config: &Config,
+) -> &'static str {
+    let side = match state {
+        State::HunkPlus(_) | State::HunkPlusWrapped => Right,
+        _ => Left,
+    };
+    let line_width = match line_numbers_data {
+        Some(data) => side_by_side::available_line_width(config, data)[side],
+        None => config.side_by_side_data[side].width,
+    };
+    // `line` has its "+/-/ " prefix but, unlike the lines checked when painting, no newline.
+    if line.graphemes(true).count() <= line_width + 1 {
+        "none"
+    } else if config.wrap_config.max_lines == 1 {
+        "truncate"
+    } else {
+        "wrap"
+    }
+}
+
+/// Format `s` as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::json_string;
+    use crate::tests::integration_test_utils;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\td\x1b"), r#""a\"b\\c\td\u001b""#);
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let config = integration_test_utils::make_config_from_args(&["--dry-run"]);
+        let output = integration_test_utils::run_delta(DIFF, &config);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), DIFF.lines().count());
+        assert!(lines[4].starts_with(r#"{"line": 5, "state": "hunk-header", "#));
+        assert!(lines[5].starts_with(r#"{"line": 6, "state": "hunk-minus", "style": "#));
+        assert!(lines[5].ends_with(r#""wrap": null, "panel": null, "text": "-a"}"#));
+
+        let config =
+            integration_test_utils::make_config_from_args(&["--dry-run", "--side-by-side"]);
+        let output = integration_test_utils::run_delta(DIFF, &config);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[5].ends_with(r#""wrap": "none", "panel": "left", "text": "-a"}"#));
+        assert!(lines[6].ends_with(r#""wrap": "none", "panel": "right", "text": "+b"}"#));
+
+        for args in &[
+            &["--side-by-side-direction", "rtl"][..],
+            &["--invert-diff"][..],
+        ] {
+            let config = integration_test_utils::make_config_from_args(
+                &[&["--dry-run", "--side-by-side"][..], *args].concat(),
+            );
+            let output = integration_test_utils::run_delta(DIFF, &config);
+            let lines: Vec<&str> = output.lines().collect();
+            assert!(lines[5].ends_with(r#""wrap": "none", "panel": "right", "text": "-a"}"#));
+            assert!(lines[6].ends_with(r#""wrap": "none", "panel": "left", "text": "+b"}"#));
+        }
+    }
+
+    const DIFF: &str = "\
+diff --git a/a.txt b/a.txt
+index 7898192..6178079 100644
+--- a/a.txt
++++ b/a.txt
+@@ -1 +1 @@
+-a
++b
+";
+}
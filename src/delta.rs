@@ -5,9 +5,12 @@ use std::io::Write;
 use bytelines::ByteLines;
 
 use crate::ansi;
+use crate::ci_annotations::HunkAnnotation;
 use crate::config::Config;
+use crate::debug_output;
 use crate::features;
 use crate::handlers;
+use crate::output::{self, OutputFormat};
 use crate::paint::Painter;
 use crate::style::DecorationStyle;
 
@@ -28,6 +31,25 @@ pub enum State {
     HunkPlusWrapped,  // Wrapped added line
 }
 
+/// The hunk lines whose line endings are displayed by --show-line-endings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineEndingsOn {
+    Minus,
+    Plus,
+    Context,
+    All,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+pub const LF_SYMBOL: &str = "↵";
+pub const CRLF_SYMBOL: &str = "↵⬛";
+pub const LONE_CR_SYMBOL: &str = "←";
+
 #[derive(Debug, PartialEq)]
 pub enum Source {
     GitDiff,     // Coming from a `git diff` command
@@ -57,6 +79,7 @@ pub struct StateMachine<'a> {
     pub minus_file_event: handlers::file_meta::FileEvent,
     pub plus_file_event: handlers::file_meta::FileEvent,
     pub diff_line: String,
+    pub line_ending: LineEnding,
     pub painter: Painter<'a>,
     pub config: &'a Config,
 
@@ -67,13 +90,38 @@ pub struct StateMachine<'a> {
     // avoid emitting the file meta header line twice (#245).
     pub current_file_pair: Option<(String, String)>,
     pub handled_file_meta_header_line_file_pair: Option<(String, String)>,
+
+    // Diff stat file entries are buffered until the end of the diff stat, so that their bars can
+    // be scaled relative to each other. See --file-stat-bar-style.
+    pub diff_stat_entries: Vec<handlers::diff_stat::DiffStatEntry>,
+
+    // With --suppress-empty-hunk-context, the hunk header and the lines of a hunk are held back
+    // until its first changed line, and are discarded if the hunk does not have one.
+    pub held_back_hunk_has_changes: bool,
+    pub held_back_hunk_header: Option<(String, String)>,
+    pub held_back_hunk_lines: Vec<(String, String, LineEnding)>,
+
+    // With --github-actions-annotations, the annotation of the current hunk, which is written
+    // when the hunk ends.
+    pub hunk_annotation: Option<HunkAnnotation>,
 }
 
-pub fn delta<I>(lines: ByteLines<I>, writer: &mut dyn Write, config: &Config) -> std::io::Result<()>
+pub fn delta<I>(reader: I, writer: &mut dyn Write, config: &Config) -> std::io::Result<()>
 where
     I: BufRead,
 {
-    StateMachine::new(writer, config).consume(lines)
+    if config.dry_run {
+        // Normal output is discarded, the description of each line is written instead.
+        let mut sink = std::io::sink();
+        return StateMachine::new(&mut sink, config).consume(reader, Some(writer));
+    }
+    if config.copy_mode {
+        return output::copy_mode::write_copy_mode(reader, writer, config);
+    }
+    if config.output_format == OutputFormat::ContextDiff {
+        return output::context_diff::write_context_diff(ByteLines::new(reader), writer, config);
+    }
+    StateMachine::new(writer, config).consume(reader, None)
 }
 
 impl<'a> StateMachine<'a> {
@@ -88,25 +136,50 @@ impl<'a> StateMachine<'a> {
             minus_file_event: handlers::file_meta::FileEvent::NoEvent,
             plus_file_event: handlers::file_meta::FileEvent::NoEvent,
             diff_line: "".to_string(),
+            line_ending: LineEnding::Lf,
             current_file_pair: None,
             handled_file_meta_header_line_file_pair: None,
+            diff_stat_entries: Vec::new(),
+            held_back_hunk_has_changes: false,
+            held_back_hunk_header: None,
+            held_back_hunk_lines: Vec::new(),
+            hunk_annotation: None,
             painter: Painter::new(writer, config),
             config,
         }
     }
 
-    fn consume<I>(&mut self, mut lines: ByteLines<I>) -> std::io::Result<()>
+    pub fn consume<I>(
+        &mut self,
+        mut reader: I,
+        mut dry_run_writer: Option<&mut dyn Write>,
+    ) -> std::io::Result<()>
     where
         I: BufRead,
     {
-        while let Some(Ok(raw_line_bytes)) = lines.next() {
-            self.ingest_line(raw_line_bytes);
+        // Lines are read including their terminator (rather than with bytelines::ByteLines, which
+        // removes a CRLF in its entirety), so that --show-line-endings can tell LF from CRLF.
+        let mut buffer = Vec::new();
+        let mut line_number = 0;
+        loop {
+            buffer.clear();
+            match reader.read_until(b'\n', &mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+            line_number += 1;
+            self.ingest_line(&buffer);
 
             if self.source == Source::Unknown {
                 self.source = detect_source(&self.line);
             }
 
+            if !self.test_buffered_diff_stat_line() {
+                self.emit_diff_stat_entries()?;
+            }
+
             let _ = self.handle_commit_meta_header_line()?
+                || self.handle_commit_meta_date_line()?
                 || self.handle_diff_stat_line()?
                 || self.handle_file_meta_diff_line()?
                 || self.handle_file_meta_minus_line()?
@@ -118,14 +191,47 @@ impl<'a> StateMachine<'a> {
                 || self.handle_hunk_line()?
                 || self.should_skip_line()
                 || self.emit_line_unchanged()?;
+
+            if let Some(writer) = dry_run_writer.as_mut() {
+                debug_output::emit_line_info(
+                    *writer,
+                    line_number,
+                    &self.line,
+                    &self.state,
+                    &self.painter.line_numbers_data,
+                    self.config,
+                )?;
+            }
         }
 
-        self.painter.paint_buffered_minus_and_plus_lines();
+        self.emit_diff_stat_entries()?;
+        self.painter.paint_buffered_lines_at_hunk_end();
         self.painter.emit()?;
+        self.write_hunk_annotation()?;
         Ok(())
     }
 
+    /// Write the --github-actions-annotations annotation of the hunk which has just ended, if any.
+    pub fn write_hunk_annotation(&mut self) -> std::io::Result<()> {
+        if let Some(annotation) = self.hunk_annotation.take() {
+            annotation.write(&mut std::io::stderr())?;
+        }
+        Ok(())
+    }
+
+    /// Ingest a line as read from the input, i.e. including its line terminator, if any.
     fn ingest_line(&mut self, raw_line_bytes: &[u8]) {
+        let mut raw_line_bytes = raw_line_bytes;
+        self.line_ending = LineEnding::Lf;
+        if let Some(b'\n') = raw_line_bytes.last() {
+            raw_line_bytes = &raw_line_bytes[..raw_line_bytes.len() - 1];
+            if self.config.show_line_endings {
+                self.line_ending = detect_line_ending(raw_line_bytes);
+            }
+            if let Some(b'\r') = raw_line_bytes.last() {
+                raw_line_bytes = &raw_line_bytes[..raw_line_bytes.len() - 1];
+            }
+        }
         // TODO: retain raw_line as Cow
         self.raw_line = String::from_utf8_lossy(raw_line_bytes).to_string();
         if self.config.max_line_length > 0 && self.raw_line.len() > self.config.max_line_length {
@@ -140,7 +246,7 @@ impl<'a> StateMachine<'a> {
 
         // Strip the neglected CR.
         // (CR-LF is unfortunately split by git because it adds ansi escapes between them.
-        //  Thus byte_lines library can't remove the CR properly.)
+        //  Thus only a CR immediately preceding the LF has been removed above.)
         if let Some(b'\r') = self.line.bytes().nth_back(0) {
             self.line.truncate(self.line.len() - 1);
         }
@@ -182,6 +288,62 @@ pub fn format_raw_line<'a>(line: &'a str, config: &Config) -> Cow<'a, str> {
     }
 }
 
+/// Whether --show-line-endings applies to hunk lines starting with `marker` ('-', '+' or ' ').
+pub fn shows_line_ending(marker: Option<char>, config: &Config) -> bool {
+    config.show_line_endings
+        && match (config.show_line_endings_on, marker) {
+            (LineEndingsOn::All, _) => true,
+            (LineEndingsOn::Minus, Some('-')) => true,
+            (LineEndingsOn::Plus, Some('+')) => true,
+            (LineEndingsOn::Context, Some(' ')) => true,
+            _ => false,
+        }
+}
+
+/// Return `line` with its line ending made visible, if --show-line-endings applies to it.
+/// `line` must be a hunk line, i.e. start with a '-', '+' or ' ' character.
+pub fn make_line_ending_visible<'a>(
+    line: &'a str,
+    line_ending: LineEnding,
+    config: &Config,
+) -> Cow<'a, str> {
+    if !shows_line_ending(line.chars().next(), config) {
+        return Cow::from(line);
+    }
+    let symbol = match line_ending {
+        LineEnding::Lf => LF_SYMBOL,
+        LineEnding::CrLf => CRLF_SYMBOL,
+    };
+    Cow::from(format!("{}{}", line.replace('\r', LONE_CR_SYMBOL), symbol))
+}
+
+/// Determine the line ending of a line from the raw bytes received by delta, with only the final
+/// LF removed. The line ending is CRLF if the line ends with a CR, which git may have separated
+/// from the LF by ANSI escape sequences.
+// Only trailing SGR sequences (ESC [ ... m) are skipped when looking for the CR.
+fn detect_line_ending(raw_line_bytes: &[u8]) -> LineEnding {
+    let mut end = raw_line_bytes.len();
+    while end > 0 && raw_line_bytes[end - 1] == b'm' {
+        let params_start = raw_line_bytes[..end - 1]
+            .iter()
+            .rposition(|b| !(b.is_ascii_digit() || *b == b';'))
+            .unwrap_or(0);
+        if params_start >= 1
+            && raw_line_bytes[params_start] == b'['
+            && raw_line_bytes[params_start - 1] == 0x1b
+        {
+            end = params_start - 1;
+        } else {
+            break;
+        }
+    }
+    if end > 0 && raw_line_bytes[end - 1] == b'\r' {
+        LineEnding::CrLf
+    } else {
+        LineEnding::Lf
+    }
+}
+
 /// Try to detect what is producing the input for delta.
 ///
 /// Currently can detect:
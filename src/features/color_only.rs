@@ -3,6 +3,9 @@ use std::collections::HashSet;
 use crate::features::raw;
 use crate::features::OptionValueFunction;
 
+pub const DESCRIPTION: &str =
+    "Only add color, without changing the structure of the input (e.g. for git add -p)";
+
 /// color-only is like raw but does not override these styles.
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     let styles: HashSet<_> = [
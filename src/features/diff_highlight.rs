@@ -1,6 +1,8 @@
 use crate::features::raw;
 use crate::features::OptionValueFunction;
 
+pub const DESCRIPTION: &str = "Emulate the output of git's contrib/diff-highlight script";
+
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     _make_feature(false)
 }
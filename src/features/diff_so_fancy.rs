@@ -1,6 +1,8 @@
 use crate::features::diff_highlight;
 use crate::features::OptionValueFunction;
 
+pub const DESCRIPTION: &str = "Emulate the output of diff-so-fancy";
+
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     let mut feature = diff_highlight::_make_feature(true);
     feature.extend(builtin_feature!([
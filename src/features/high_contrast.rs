@@ -0,0 +1,102 @@
+use crate::features::OptionValueFunction;
+
+pub const DESCRIPTION: &str = "Use bright colors on black for removed, added and unchanged lines";
+
+/// A palette of bright colors on black, with explicit foreground colors so that neither syntax
+/// highlighting nor the terminal's colors reduce the contrast.
+pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
+    builtin_feature!([
+        (
+            "minus-style",
+            String,
+            None,
+            _opt => "bold brightred black"
+        ),
+        (
+            "minus-non-emph-style",
+            String,
+            None,
+            opt => opt.minus_style.clone()
+        ),
+        (
+            "minus-emph-style",
+            String,
+            None,
+            _opt => "bold black brightred"
+        ),
+        (
+            "zero-style",
+            String,
+            None,
+            _opt => "white black"
+        ),
+        (
+            "plus-style",
+            String,
+            None,
+            _opt => "bold brightgreen black"
+        ),
+        (
+            "plus-non-emph-style",
+            String,
+            None,
+            opt => opt.plus_style.clone()
+        ),
+        (
+            "plus-emph-style",
+            String,
+            None,
+            _opt => "bold black brightgreen"
+        ),
+        (
+            "hunk-header-style",
+            String,
+            None,
+            _opt => "line-number bold white"
+        ),
+        (
+            "line-numbers-minus-style",
+            String,
+            None,
+            _opt => "brightred"
+        ),
+        (
+            "line-numbers-zero-style",
+            String,
+            None,
+            _opt => "white"
+        ),
+        (
+            "line-numbers-plus-style",
+            String,
+            None,
+            _opt => "brightgreen"
+        )
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::integration_test_utils;
+
+    #[test]
+    fn test_high_contrast() {
+        let opt = integration_test_utils::make_options_from_args_and_git_config(
+            &["--high-contrast"],
+            None,
+            None,
+        );
+        assert_eq!(opt.minus_style, "bold brightred black");
+        assert_eq!(opt.minus_non_emph_style, "bold brightred black");
+        assert_eq!(opt.zero_style, "white black");
+        assert_eq!(opt.plus_style, "bold brightgreen black");
+        assert_eq!(opt.plus_non_emph_style, "bold brightgreen black");
+
+        let opt = integration_test_utils::make_options_from_args_and_git_config(
+            &["--high-contrast", "--minus-style", "bold yellow black"],
+            None,
+            None,
+        );
+        assert_eq!(opt.minus_style, "bold yellow black");
+    }
+}
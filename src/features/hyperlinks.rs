@@ -8,6 +8,9 @@ use crate::config::Config;
 use crate::features::OptionValueFunction;
 use crate::git_config::{GitConfig, GitConfigEntry, GitRemoteRepo};
 
+pub const DESCRIPTION: &str =
+    "Render commit hashes, file names and line numbers as terminal hyperlinks";
+
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     builtin_feature!([
         (
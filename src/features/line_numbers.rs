@@ -7,12 +7,14 @@ use crate::config;
 use crate::delta::State;
 use crate::features::hyperlinks;
 use crate::features::side_by_side::ansifill::{self, ODD_PAD_CHAR};
-use crate::features::side_by_side::{Left, PanelSide, Right};
+use crate::features::side_by_side::{Left, PanelSide, Right, SideBySideDirection};
 use crate::features::OptionValueFunction;
 use crate::format::{self, Align, Placeholder};
 use crate::minusplus::*;
 use crate::style::Style;
 
+pub const DESCRIPTION: &str = "Display line numbers next to the diff";
+
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     builtin_feature!([
         (
@@ -68,8 +70,12 @@ pub fn linenumbers_and_styles<'a>(
     config: &'a config::Config,
     increment: bool,
 ) -> Option<(MinusPlus<Option<usize>>, MinusPlus<Style>)> {
-    let nr_left = line_numbers_data.line_number[Left];
-    let nr_right = line_numbers_data.line_number[Right];
+    let line_number = if config.line_numbers_relative_to_hunk {
+        &line_numbers_data.hunk_line_counter
+    } else {
+        &line_numbers_data.line_number
+    };
+    let (nr_left, nr_right) = (line_number[Left], line_number[Right]);
     let (minus_style, zero_style, plus_style) = (
         config.line_numbers_style_minusplus[Minus],
         config.line_numbers_zero_style,
@@ -77,23 +83,30 @@ pub fn linenumbers_and_styles<'a>(
     );
     let ((minus_number, plus_number), (minus_style, plus_style)) = match state {
         State::HunkMinus(_) => {
-            line_numbers_data.line_number[Left] += increment as usize;
+            line_numbers_data.advance(Left, increment);
             ((Some(nr_left), None), (minus_style, plus_style))
         }
         State::HunkMinusWrapped => ((None, None), (minus_style, plus_style)),
         State::HunkZero => {
-            line_numbers_data.line_number[Left] += increment as usize;
-            line_numbers_data.line_number[Right] += increment as usize;
+            line_numbers_data.advance(Left, increment);
+            line_numbers_data.advance(Right, increment);
             ((Some(nr_left), Some(nr_right)), (zero_style, zero_style))
         }
         State::HunkZeroWrapped => ((None, None), (zero_style, zero_style)),
         State::HunkPlus(_) => {
-            line_numbers_data.line_number[Right] += increment as usize;
+            line_numbers_data.advance(Right, increment);
             ((None, Some(nr_right)), (minus_style, plus_style))
         }
         State::HunkPlusWrapped => ((None, None), (minus_style, plus_style)),
         _ => return None,
     };
+    // With --invert-diff, {nm} and {np} swap roles. In side-by-side mode each panel keeps its
+    // line numbers, the panels themselves are swapped (see `Config::side_by_side_direction`).
+    let (minus_number, plus_number) = if config.invert_diff && !config.side_by_side {
+        (plus_number, minus_number)
+    } else {
+        (minus_number, plus_number)
+    };
     Some((
         MinusPlus::new(minus_number, plus_number),
         MinusPlus::new(minus_style, plus_style),
@@ -104,6 +117,7 @@ pub fn linenumbers_and_styles<'a>(
 /// two-column line number display.
 pub fn format_and_paint_line_numbers<'a>(
     line_numbers_data: &'a LineNumbersData,
+    state: &State,
     side_by_side_panel: Option<PanelSide>,
     styles: MinusPlus<Style>,
     line_numbers: MinusPlus<Option<usize>>,
@@ -118,10 +132,19 @@ pub fn format_and_paint_line_numbers<'a>(
         (true, None) => unreachable!(),
     };
 
+    // The format of the left column is replaced for minus lines, the one of the right column for
+    // plus lines.
+    let changed_side = match state {
+        State::HunkMinus(_) | State::HunkMinusWrapped => Some(Minus),
+        State::HunkPlus(_) | State::HunkPlusWrapped => Some(Plus),
+        _ => None,
+    };
+
     if emit_left {
         formatted_numbers.extend(format_and_paint_line_number_field(
             line_numbers_data,
             Minus,
+            changed_side == Some(Minus),
             &styles,
             &line_numbers,
             config,
@@ -132,6 +155,7 @@ pub fn format_and_paint_line_numbers<'a>(
         formatted_numbers.extend(format_and_paint_line_number_field(
             line_numbers_data,
             Plus,
+            changed_side == Some(Plus),
             &styles,
             &line_numbers,
             config,
@@ -140,6 +164,9 @@ pub fn format_and_paint_line_numbers<'a>(
     formatted_numbers
 }
 
+/// The character separating the columns of side-by-side mode in the line number formats.
+const COLUMN_SEPARATOR: &str = "│";
+
 lazy_static! {
     static ref LINE_NUMBERS_PLACEHOLDER_REGEX: Regex = format::make_placeholder_regex(&["nm", "np"]);
 }
@@ -147,7 +174,12 @@ lazy_static! {
 #[derive(Default, Debug)]
 pub struct LineNumbersData<'a> {
     pub format_data: MinusPlus<format::FormatStringData<'a>>,
+    /// Replacements of `format_data` for minus lines (left column) and plus lines (right column).
+    pub changed_line_format_data: MinusPlus<Option<format::FormatStringData<'a>>>,
     pub line_number: MinusPlus<usize>,
+    /// The line numbers relative to the start of the current hunk, see
+    /// --line-numbers-relative-to-hunk.
+    pub hunk_line_counter: MinusPlus<usize>,
     pub hunk_max_line_number_width: usize,
     pub plus_file: String,
 }
@@ -160,48 +192,87 @@ impl<'a> LineNumbersData<'a> {
     pub fn from_format_strings(
         format: &'a MinusPlus<String>,
         use_full_width: ansifill::UseFullPanelWidth,
+        direction: SideBySideDirection,
     ) -> LineNumbersData<'a> {
-        let insert_center_space_on_odd_width = use_full_width.pad_width();
+        // The center space is inserted into the rightmost panel.
+        let insert_center_space_on_odd_width =
+            |side: PanelSide| use_full_width.pad_width() && side == direction.panels()[1];
         Self {
             format_data: MinusPlus::new(
                 format::parse_line_number_format(
                     &format[Left],
                     &*LINE_NUMBERS_PLACEHOLDER_REGEX,
-                    false,
+                    insert_center_space_on_odd_width(Left),
                 ),
                 format::parse_line_number_format(
                     &format[Right],
                     &*LINE_NUMBERS_PLACEHOLDER_REGEX,
-                    insert_center_space_on_odd_width,
+                    insert_center_space_on_odd_width(Right),
                 ),
             ),
             ..Self::default()
         }
     }
 
+    /// Set the formats replacing the left column format for minus lines and the right column
+    /// format for plus lines, see --line-numbers-minus-format and --line-numbers-plus-format.
+    pub fn with_changed_line_formats(
+        mut self,
+        format: &'a MinusPlus<Option<String>>,
+        use_full_width: ansifill::UseFullPanelWidth,
+        direction: SideBySideDirection,
+    ) -> LineNumbersData<'a> {
+        let insert_center_space_on_odd_width =
+            |side: PanelSide| use_full_width.pad_width() && side == direction.panels()[1];
+        let parse = move |side: PanelSide| {
+            format[side].as_ref().map(|format| {
+                format::parse_line_number_format(
+                    format,
+                    &*LINE_NUMBERS_PLACEHOLDER_REGEX,
+                    insert_center_space_on_odd_width(side),
+                )
+            })
+        };
+        self.changed_line_format_data = MinusPlus::new(parse(Left), parse(Right));
+        self
+    }
+
     /// Initialize line number data for a hunk.
     pub fn initialize_hunk(&mut self, line_numbers: &[(usize, usize)], plus_file: String) {
         // Typically, line_numbers has length 2: an entry for the minus file, and one for the plus
         // file. In the case of merge commits, it may be longer.
         self.line_number =
             MinusPlus::new(line_numbers[0].0, line_numbers[line_numbers.len() - 1].0);
+        self.hunk_line_counter = MinusPlus::new(1, 1);
         let hunk_max_line_number = line_numbers.iter().map(|(n, d)| n + d).max().unwrap();
         self.hunk_max_line_number_width =
             1 + (hunk_max_line_number as f64).log10().floor() as usize;
         self.plus_file = plus_file;
     }
 
-    pub fn empty_for_sbs(use_full_width: ansifill::UseFullPanelWidth) -> LineNumbersData<'a> {
+    /// Move on to the next line of `side`, if `increment` is set.
+    fn advance(&mut self, side: PanelSide, increment: bool) {
+        self.line_number[side] += increment as usize;
+        self.hunk_line_counter[side] += increment as usize;
+    }
+
+    pub fn empty_for_sbs(
+        use_full_width: ansifill::UseFullPanelWidth,
+        direction: SideBySideDirection,
+    ) -> LineNumbersData<'a> {
         let insert_center_space_on_odd_width = use_full_width.pad_width();
         Self {
             format_data: if insert_center_space_on_odd_width {
-                let format_left = vec![format::FormatStringPlaceholderData::default()];
-                let format_right = vec![format::FormatStringPlaceholderData {
+                let mut format_data = MinusPlus::new(
+                    vec![format::FormatStringPlaceholderData::default()],
+                    vec![format::FormatStringPlaceholderData::default()],
+                );
+                format_data[direction.panels()[1]] = vec![format::FormatStringPlaceholderData {
                     prefix: format!("{}", ODD_PAD_CHAR).into(),
                     prefix_len: 1,
                     ..Default::default()
                 }];
-                MinusPlus::new(format_left, format_right)
+                format_data
             } else {
                 MinusPlus::default()
             },
@@ -240,20 +311,30 @@ impl<'a> LineNumbersData<'a> {
 fn format_and_paint_line_number_field<'a>(
     line_numbers_data: &'a LineNumbersData,
     side: MinusPlusIndex,
+    is_changed_line: bool,
     styles: &MinusPlus<Style>,
     line_numbers: &MinusPlus<Option<usize>>,
     config: &config::Config,
 ) -> Vec<ansi_term::ANSIGenericString<'a, str>> {
     let min_field_width = line_numbers_data.hunk_max_line_number_width;
 
-    let format_data = &line_numbers_data.format_data[side];
+    let format_data = match &line_numbers_data.changed_line_format_data[side] {
+        Some(changed_line_format_data) if is_changed_line => changed_line_format_data,
+        _ => &line_numbers_data.format_data[side],
+    };
     let plus_file = &line_numbers_data.plus_file;
     let style = &config.line_numbers_style_leftright[side];
 
     let mut ansi_strings = Vec::new();
     let mut suffix = "";
-    for placeholder in format_data {
-        ansi_strings.push(style.paint(placeholder.prefix.as_str()));
+    for (i, placeholder) in format_data.iter().enumerate() {
+        ansi_strings.extend(paint_format_text(
+            placeholder.prefix.as_str(),
+            style,
+            i == 0,
+            side,
+            config,
+        ));
 
         let width = if let Some(placeholder_width) = placeholder.width {
             max(placeholder_width, min_field_width)
@@ -270,11 +351,17 @@ fn format_and_paint_line_number_field<'a>(
                 format_line_number(line_numbers[Minus], alignment_spec, width, None, config),
             )),
             Some(Placeholder::NumberPlus) => {
+                // A hyperlink to a line relative to the hunk would point at the wrong line.
+                let hyperlink_file = if config.line_numbers_relative_to_hunk {
+                    None
+                } else {
+                    Some(plus_file.as_str())
+                };
                 ansi_strings.push(styles[Plus].paint(format_line_number(
                     line_numbers[Plus],
                     alignment_spec,
                     width,
-                    Some(plus_file),
+                    hyperlink_file,
                     config,
                 )))
             }
@@ -283,7 +370,44 @@ fn format_and_paint_line_number_field<'a>(
         }
         suffix = placeholder.suffix.as_str();
     }
-    ansi_strings.push(style.paint(suffix));
+    ansi_strings.extend(paint_format_text(suffix, style, false, side, config));
+    ansi_strings
+}
+
+/// Paint the literal text of a line number format. In side-by-side mode the column separators in
+/// it are painted in their own style, if one is configured: the one starting the format of a panel
+/// is either before the left panel or between the panels.
+fn paint_format_text<'a>(
+    text: &'a str,
+    style: &Style,
+    is_start_of_format: bool,
+    side: MinusPlusIndex,
+    config: &config::Config,
+) -> Vec<ansi_term::ANSIGenericString<'a, str>> {
+    if !config.side_by_side {
+        return vec![style.paint(text)];
+    }
+    let mut ansi_strings = Vec::new();
+    let mut start = 0;
+    for (i, separator) in text.match_indices(COLUMN_SEPARATOR) {
+        let separator_style = if is_start_of_format && i == 0 {
+            if config.side_by_side_direction.is_leftmost(side) {
+                config.separator_before_left_panel_style
+            } else {
+                config.separator_between_panels_style
+            }
+        } else {
+            config.side_by_side_column_separator_style
+        };
+        if let Some(separator_style) = separator_style {
+            if start < i {
+                ansi_strings.push(style.paint(&text[start..i]));
+            }
+            ansi_strings.push(separator_style.paint(separator));
+            start = i + separator.len();
+        }
+    }
+    ansi_strings.push(style.paint(&text[start..]));
     ansi_strings
 }
 
@@ -570,32 +694,33 @@ pub mod tests {
     #[test]
     fn test_line_numbers_data() {
         use crate::features::side_by_side::ansifill;
+        const LTR: SideBySideDirection = SideBySideDirection::LeftToRight;
         let w = ansifill::UseFullPanelWidth(false);
         let format = MinusPlus::new("".into(), "".into());
-        let mut data = LineNumbersData::from_format_strings(&format, w.clone());
+        let mut data = LineNumbersData::from_format_strings(&format, w.clone(), LTR);
         data.initialize_hunk(&[(10, 11), (10000, 100001)], "a".into());
         assert_eq!(data.formatted_width(), MinusPlus::new(0, 0));
 
         let format = MinusPlus::new("│".into(), "│+│".into());
-        let mut data = LineNumbersData::from_format_strings(&format, w.clone());
+        let mut data = LineNumbersData::from_format_strings(&format, w.clone(), LTR);
 
         data.initialize_hunk(&[(10, 11), (10000, 100001)], "a".into());
         assert_eq!(data.formatted_width(), MinusPlus::new(1, 3));
 
         let format = MinusPlus::new("│{nm:^3}│".into(), "│{np:^3}│".into());
-        let mut data = LineNumbersData::from_format_strings(&format, w.clone());
+        let mut data = LineNumbersData::from_format_strings(&format, w.clone(), LTR);
 
         data.initialize_hunk(&[(10, 11), (10000, 100001)], "a".into());
         assert_eq!(data.formatted_width(), MinusPlus::new(8, 8));
 
         let format = MinusPlus::new("│{nm:^3}│ │{np:<12}│ │{nm}│".into(), "".into());
-        let mut data = LineNumbersData::from_format_strings(&format, w.clone());
+        let mut data = LineNumbersData::from_format_strings(&format, w.clone(), LTR);
 
         data.initialize_hunk(&[(10, 11), (10000, 100001)], "a".into());
         assert_eq!(data.formatted_width(), MinusPlus::new(32, 0));
 
         let format = MinusPlus::new("│{np:^3}│ │{nm:<12}│ │{np}│".into(), "".into());
-        let mut data = LineNumbersData::from_format_strings(&format, w.clone());
+        let mut data = LineNumbersData::from_format_strings(&format, w.clone(), LTR);
 
         data.initialize_hunk(&[(10, 11), (10000, 100001)], "a".into());
         assert_eq!(data.formatted_width(), MinusPlus::new(32, 0));
@@ -678,6 +803,166 @@ pub mod tests {
         assert_eq!(lines.next().unwrap(), "    ⋮ 2  │bb = 2");
     }
 
+    #[test]
+    fn test_syntax_padding() {
+        let config = make_config_from_args(&[
+            "--line-numbers",
+            "--line-numbers-left-format",
+            "{nm:^4}⋮",
+            "--line-numbers-right-format",
+            "{np:^4}│",
+            "--syntax-padding",
+            "1",
+        ]);
+        let output = run_delta(ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        assert_eq!(lines.next().unwrap(), " 1  ⋮ 1  │a = 1");
+        assert_eq!(lines.next().unwrap(), "    ⋮    │");
+        assert_eq!(lines.next().unwrap(), " 2  ⋮    │b = 2");
+        assert_eq!(lines.next().unwrap(), "    ⋮ 2  │bb = 2");
+        assert_eq!(lines.next().unwrap(), "    ⋮    │");
+        assert_eq!(lines.next().unwrap(), " 3  ⋮ 3  │c = 3");
+        assert_eq!(lines.next(), None);
+
+        let config =
+            make_config_from_args(&["--side-by-side", "--width", "40", "--syntax-padding", "1"]);
+        let output = run_delta(ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        assert_eq!(lines.next().unwrap(), "│ 1  │a = 1         │ 1  │a = 1");
+        assert_eq!(lines.next().unwrap(), "│    │              │    │");
+        assert_eq!(lines.next().unwrap(), "│ 2  │b = 2         │ 2  │bb = 2");
+        assert_eq!(lines.next().unwrap(), "│    │              │    │");
+        assert_eq!(lines.next().unwrap(), "│ 3  │c = 3         │ 3  │c = 3");
+    }
+
+    #[test]
+    fn test_context_before_and_after() {
+        let args = [
+            "--line-numbers",
+            "--line-numbers-left-format",
+            "{nm:^4}⋮",
+            "--line-numbers-right-format",
+            "{np:^4}│",
+        ];
+        let config = make_config_from_args(&[&args[..], &["--context-before", "0"]].concat());
+        let output = run_delta(ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        assert_eq!(lines.next().unwrap(), " 2  ⋮    │b = 2");
+        assert_eq!(lines.next().unwrap(), "    ⋮ 2  │bb = 2");
+        assert_eq!(lines.next().unwrap(), " 3  ⋮ 3  │c = 3");
+        assert_eq!(lines.next(), None);
+
+        let config = make_config_from_args(
+            &[
+                &args[..],
+                &["--context-after", "0", "--syntax-padding", "1"],
+            ]
+            .concat(),
+        );
+        let output = run_delta(ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        assert_eq!(lines.next().unwrap(), " 1  ⋮ 1  │a = 1");
+        assert_eq!(lines.next().unwrap(), "    ⋮    │");
+        assert_eq!(lines.next().unwrap(), " 2  ⋮    │b = 2");
+        assert_eq!(lines.next().unwrap(), "    ⋮ 2  │bb = 2");
+        assert_eq!(lines.next().unwrap(), "    ⋮    │");
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_context_after_without_newline_at_end_of_file() {
+        let config = make_config_from_args(&[
+            "--line-numbers",
+            "--line-numbers-left-format",
+            "{nm:^4}⋮",
+            "--line-numbers-right-format",
+            "{np:^4}│",
+            "--context-after",
+            "0",
+        ]);
+        let input = format!(
+            "{}\\ No newline at end of file\n",
+            ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF
+        );
+        let output = run_delta(&input, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        assert_eq!(lines.next().unwrap(), " 1  ⋮ 1  │a = 1");
+        assert_eq!(lines.next().unwrap(), " 2  ⋮    │b = 2");
+        assert_eq!(lines.next().unwrap(), "    ⋮ 2  │bb = 2");
+        assert_eq!(lines.next().unwrap(), "\\ No newline at end of file");
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_invert_diff() {
+        let args = [
+            "--line-numbers",
+            "--line-numbers-left-format",
+            "{nm:^4}⋮",
+            "--line-numbers-right-format",
+            "{np:^4}│",
+        ];
+        let default_config = make_config_from_args(&args);
+        let config = make_config_from_args(&[&args[..], &["--invert-diff"]].concat());
+        assert_eq!(config.minus_style, default_config.plus_style);
+        assert_eq!(config.plus_style, default_config.minus_style);
+        assert_eq!(config.minus_emph_style, default_config.plus_emph_style);
+        assert_eq!(config.plus_emph_style, default_config.minus_emph_style);
+
+        let output = run_delta(ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        assert_eq!(lines.next().unwrap(), " 1  ⋮ 1  │a = 1");
+        assert_eq!(lines.next().unwrap(), "    ⋮ 2  │b = 2");
+        assert_eq!(lines.next().unwrap(), " 2  ⋮    │bb = 2");
+        assert_eq!(lines.next().unwrap(), " 3  ⋮ 3  │c = 3");
+    }
+
+    #[test]
+    fn test_line_numbers_minus_and_plus_format() {
+        let config = make_config_from_args(&[
+            "--line-numbers",
+            "--line-numbers-left-format",
+            "{nm:^4}⋮",
+            "--line-numbers-right-format",
+            "{np:^4}│",
+            "--line-numbers-minus-format",
+            "{nm:^4}*",
+            "--line-numbers-plus-format",
+            "{np:^4}+",
+        ]);
+        let output = run_delta(ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        assert_eq!(lines.next().unwrap(), " 1  ⋮ 1  │a = 1");
+        assert_eq!(lines.next().unwrap(), " 2  *    │b = 2");
+        assert_eq!(lines.next().unwrap(), "    ⋮ 2  +bb = 2");
+        assert_eq!(lines.next().unwrap(), " 3  ⋮ 3  │c = 3");
+    }
+
+    #[test]
+    fn test_line_numbers_relative_to_hunk() {
+        let config = make_config_from_args(&[
+            "--line-numbers",
+            "--line-numbers-left-format",
+            "{nm:^4}⋮",
+            "--line-numbers-right-format",
+            "{np:^4}│",
+            "--line-numbers-relative-to-hunk",
+        ]);
+        let output = strip_ansi_codes(&run_delta(TWO_LINE_DIFFS, &config));
+        assert!(output.contains("\n 1  ⋮ 1  │a = 1\n"));
+        assert!(output.contains("\n 1  ⋮ 1  │a = 3\n"));
+        assert!(output.contains("\n 2  ⋮    │b = 4\n"));
+        assert!(output.contains("\n    ⋮ 2  │bb = 4\n"));
+        assert!(!output.contains("499  │"));
+    }
+
     #[test]
     fn test_repeated_placeholder() {
         let config = make_config_from_args(&[
@@ -782,6 +1067,18 @@ index 223ca50..367a6f6 100644
 +bb = 2
 ";
 
+    const ONE_MINUS_ONE_PLUS_LINE_WITH_CONTEXT_DIFF: &str = "\
+diff --git i/a.py w/a.py
+index 223ca50..e1c3f9b 100644
+--- i/a.py
++++ w/a.py
+@@ -1,3 +1,3 @@
+ a = 1
+-b = 2
++bb = 2
+ c = 3
+";
+
     const TWO_LINE_DIFFS: &str = "\
 diff --git i/a.py w/a.py
 index 223ca50..367a6f6 100644
@@ -21,43 +21,67 @@ pub type BuiltinFeature = HashMap<String, OptionValueFunction>;
 
 type OptionValueFunction = Box<dyn Fn(&cli::Opt, &Option<GitConfig>) -> ProvenancedOptionValue>;
 
+type MakeFeatureFunction = fn() -> Vec<(String, OptionValueFunction)>;
+
+/// The builtin features as (name, description, make_feature), in the order displayed by
+/// --list-features. Each feature module supplies the one-line DESCRIPTION along with its
+/// make_feature function.
+const BUILTIN_FEATURES: &[(&str, &str, MakeFeatureFunction)] = &[
+    (
+        "color-only",
+        color_only::DESCRIPTION,
+        color_only::make_feature,
+    ),
+    (
+        "diff-highlight",
+        diff_highlight::DESCRIPTION,
+        diff_highlight::make_feature,
+    ),
+    (
+        "diff-so-fancy",
+        diff_so_fancy::DESCRIPTION,
+        diff_so_fancy::make_feature,
+    ),
+    (
+        "high-contrast",
+        high_contrast::DESCRIPTION,
+        high_contrast::make_feature,
+    ),
+    (
+        "hyperlinks",
+        hyperlinks::DESCRIPTION,
+        hyperlinks::make_feature,
+    ),
+    (
+        "line-numbers",
+        line_numbers::DESCRIPTION,
+        line_numbers::make_feature,
+    ),
+    ("navigate", navigate::DESCRIPTION, navigate::make_feature),
+    ("raw", raw::DESCRIPTION, raw::make_feature),
+    (
+        "side-by-side",
+        side_by_side::DESCRIPTION,
+        side_by_side::make_feature,
+    ),
+];
+
 // Construct a 2-level hash map: (feature name) -> (option name) -> (value function). A value
 // function is a function that takes an Opt struct, and a git Config struct, and returns the value
 // for the option.
 pub fn make_builtin_features() -> HashMap<String, BuiltinFeature> {
-    vec![
-        (
-            "color-only".to_string(),
-            color_only::make_feature().into_iter().collect(),
-        ),
-        (
-            "diff-highlight".to_string(),
-            diff_highlight::make_feature().into_iter().collect(),
-        ),
-        (
-            "diff-so-fancy".to_string(),
-            diff_so_fancy::make_feature().into_iter().collect(),
-        ),
-        (
-            "hyperlinks".to_string(),
-            hyperlinks::make_feature().into_iter().collect(),
-        ),
-        (
-            "line-numbers".to_string(),
-            line_numbers::make_feature().into_iter().collect(),
-        ),
-        (
-            "navigate".to_string(),
-            navigate::make_feature().into_iter().collect(),
-        ),
-        ("raw".to_string(), raw::make_feature().into_iter().collect()),
-        (
-            "side-by-side".to_string(),
-            side_by_side::make_feature().into_iter().collect(),
-        ),
-    ]
-    .into_iter()
-    .collect()
+    BUILTIN_FEATURES
+        .iter()
+        .map(|(name, _, make_feature)| (name.to_string(), make_feature().into_iter().collect()))
+        .collect()
+}
+
+/// The names and one-line descriptions of the builtin features, as displayed by --list-features.
+pub fn builtin_feature_descriptions() -> Vec<(&'static str, &'static str)> {
+    BUILTIN_FEATURES
+        .iter()
+        .map(|(name, description, _)| (*name, *description))
+        .collect()
 }
 
 /// The macro permits the values of a builtin feature to be specified as either (a) a git config
@@ -82,6 +106,7 @@ macro_rules! builtin_feature {
 pub mod color_only;
 pub mod diff_highlight;
 pub mod diff_so_fancy;
+pub mod high_contrast;
 pub mod hyperlinks;
 pub mod line_numbers;
 pub mod navigate;
@@ -94,7 +119,7 @@ pub mod tests {
     use std::fs::remove_file;
 
     use crate::cli;
-    use crate::features::make_builtin_features;
+    use crate::features::{builtin_feature_descriptions, make_builtin_features};
     use crate::tests::integration_test_utils::make_options_from_args_and_git_config;
 
     #[test]
@@ -109,6 +134,15 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_builtin_feature_descriptions() {
+        let descriptions = builtin_feature_descriptions();
+        assert_eq!(descriptions.len(), make_builtin_features().len());
+        for (name, description) in descriptions {
+            assert!(!description.is_empty(), "{} has no description", name);
+        }
+    }
+
     #[test]
     fn test_builtin_feature_from_gitconfig() {
         let git_config_contents = b"
@@ -211,6 +245,55 @@ pub mod tests {
         remove_file(git_config_path).unwrap();
     }
 
+    #[test]
+    fn test_no_feature_and_feature_priority() {
+        let git_config_contents = b"
+[delta]
+    features = my-feature-1 my-feature-2 my-feature-3
+
+[delta \"my-feature-1\"]
+    minus-style = green
+
+[delta \"my-feature-2\"]
+    minus-style = yellow
+
+[delta \"my-feature-3\"]
+    minus-style = red
+";
+        let git_config_path = "delta__test_no_feature_and_feature_priority.gitconfig";
+
+        let opt = make_options_from_args_and_git_config(
+            &["--no-feature", "my-feature-3"],
+            Some(git_config_contents),
+            Some(git_config_path),
+        );
+        assert_eq!(opt.features, "my-feature-1 my-feature-2");
+        assert_eq!(opt.minus_style, "yellow");
+
+        let opt = make_options_from_args_and_git_config(
+            &["--feature-priority", "my-feature-1 my-feature-2"],
+            Some(git_config_contents),
+            Some(git_config_path),
+        );
+        assert_eq!(opt.features, "my-feature-3 my-feature-2 my-feature-1");
+        assert_eq!(opt.minus_style, "green");
+
+        let opt = make_options_from_args_and_git_config(
+            &[
+                "--no-feature",
+                "my-feature-1",
+                "--feature-priority",
+                "my-feature-2",
+            ],
+            Some(git_config_contents),
+            Some(git_config_path),
+        );
+        assert_eq!(opt.features, "my-feature-3 my-feature-2");
+        assert_eq!(opt.minus_style, "yellow");
+
+        remove_file(git_config_path).unwrap();
+    }
+
     #[test]
     fn test_recursive_feature_gathering_2() {
         let git_config_contents = b"
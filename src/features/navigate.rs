@@ -6,6 +6,8 @@ use std::path::PathBuf;
 use crate::config::Config;
 use crate::features::OptionValueFunction;
 
+pub const DESCRIPTION: &str = "Use n and N in less to move between files and hunks";
+
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     builtin_feature!([
         (
@@ -1,5 +1,8 @@
 use crate::features::OptionValueFunction;
 
+pub const DESCRIPTION: &str =
+    "Display the diff as git does, without delta's styles and decorations";
+
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     builtin_feature!([
         (
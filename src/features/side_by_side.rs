@@ -1,6 +1,8 @@
-use itertools::Itertools;
+use std::sync::Once;
+
 use syntect::highlighting::Style as SyntectStyle;
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
 
 use crate::ansi;
 use crate::cli;
@@ -16,6 +18,8 @@ use crate::wrapping::wrap_zero_block;
 
 pub type LineSegments<'a, S> = Vec<(S, &'a str)>;
 
+pub const DESCRIPTION: &str = "Display the diff in two panels, side by side";
+
 pub fn make_feature() -> Vec<(String, OptionValueFunction)> {
     builtin_feature!([
         (
@@ -45,6 +49,29 @@ pub type LeftRight<T> = MinusPlus<T>;
 
 pub type SideBySideData = LeftRight<Panel>;
 
+/// The order in which the panels are displayed. The minus panel is on the left by default, and
+/// on the right with `RightToLeft`, e.g. for users of right-to-left languages.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SideBySideDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl SideBySideDirection {
+    /// The panels in the order in which they are displayed, from left to right.
+    pub fn panels(self) -> [PanelSide; 2] {
+        match self {
+            SideBySideDirection::LeftToRight => [Left, Right],
+            SideBySideDirection::RightToLeft => [Right, Left],
+        }
+    }
+
+    /// Whether `panel_side` is displayed as the leftmost panel.
+    pub fn is_leftmost(self, panel_side: PanelSide) -> bool {
+        self.panels()[0] == panel_side
+    }
+}
+
 impl SideBySideData {
     /// Create a [`LeftRight<Panel>`](LeftRight<Panel>) named [`SideBySideData`].
     pub fn new_sbs(decorations_width: &cli::Width, available_terminal_width: &usize) -> Self {
@@ -54,23 +81,88 @@ impl SideBySideData {
         };
         SideBySideData::new(Panel { width: panel_width }, Panel { width: panel_width })
     }
+
+    /// Whether the panels are narrower than `min_panel_width`, in which case side-by-side
+    /// output would be unreadable and normal output should be used instead.
+    pub fn panels_too_narrow(&self, min_panel_width: usize) -> bool {
+        self[Left].width < min_panel_width || self[Right].width < min_panel_width
+    }
 }
 
+/// The minimum width which should be left for the lines of a panel. If less space is left, a
+/// warning naming what takes up the width of the panel is emitted.
+pub const MIN_USABLE_WIDTH: usize = 5;
+
+static WARN_LINE_WIDTH_BELOW_MIN_USABLE_WIDTH: Once = Once::new();
+
 pub fn available_line_width(
     config: &Config,
     data: &line_numbers::LineNumbersData,
 ) -> line_numbers::SideBySideLineWidth {
-    let linennumbers_width = data.formatted_width();
+    let (line_width, warning) = available_line_width_and_warning(config, data);
+    if let Some(warning) = warning {
+        WARN_LINE_WIDTH_BELOW_MIN_USABLE_WIDTH.call_once(|| eprintln!("{}", warning));
+    }
+    line_width
+}
 
-    // The width can be reduced by the line numbers and/or a possibly kept 1-wide "+/-/ " prefix.
-    let line_width = |side: PanelSide| {
-        config.side_by_side_data[side]
-            .width
-            .saturating_sub(linennumbers_width[side])
-            .saturating_sub(config.keep_plus_minus_markers as usize)
+/// Compute the [`available_line_width`], and a warning if it is less than [`MIN_USABLE_WIDTH`]
+/// for one of the panels.
+fn available_line_width_and_warning(
+    config: &Config,
+    data: &line_numbers::LineNumbersData,
+) -> (line_numbers::SideBySideLineWidth, Option<String>) {
+    let linennumbers_width = data.formatted_width();
+    let mut warning = None;
+
+    // The width can be reduced by the line numbers, a possibly kept 1-wide "+/-/ " prefix and/or
+    // the 1-wide --syntax-line-range marker column.
+    let mut line_width = |side: PanelSide| {
+        let side_name = if side == Left { "left" } else { "right" };
+        let panel_width = config.side_by_side_data[side].width;
+        let reductions = [
+            (
+                format!("--line-numbers-{}-format", side_name),
+                linennumbers_width[side],
+            ),
+            (
+                "--keep-plus-minus-markers".to_string(),
+                config.keep_plus_minus_markers as usize,
+            ),
+            (
+                "the --syntax-line-range marker column".to_string(),
+                (config.syntax_line_range.is_some() && config.line_numbers) as usize,
+            ),
+        ];
+        let width = reductions
+            .iter()
+            .fold(panel_width, |width, (_, reduction)| {
+                width.saturating_sub(*reduction)
+            });
+        if width < MIN_USABLE_WIDTH && warning.is_none() {
+            let causes = reductions
+                .iter()
+                .filter(|(_, reduction)| *reduction > 0)
+                .map(|(cause, reduction)| format!("{} uses {}", cause, reduction))
+                .collect::<Vec<_>>();
+            warning = Some(format!(
+                "delta: the {} panel is {} columns wide (see --width){}, leaving only {} columns \
+                 for its lines.",
+                side_name,
+                panel_width,
+                if causes.is_empty() {
+                    "".to_string()
+                } else {
+                    format!(", of which {}", causes.join(" and "))
+                },
+                width,
+            ));
+        }
+        width
     };
 
-    LeftRight::new(line_width(Left), line_width(Right))
+    let line_width = LeftRight::new(line_width(Left), line_width(Right));
+    (line_width, warning)
 }
 
 pub fn line_is_too_long(line: &str, line_width: usize) -> bool {
@@ -82,6 +174,19 @@ pub fn line_is_too_long(line: &str, line_width: usize) -> bool {
     line_sum > line_width + 2
 }
 
+/// Return whether any of the input lines is too long. Unlike [`has_long_lines`]
+/// this stops at the first line which is too long.
+pub fn has_any_long_line(
+    lines: &LeftRight<&Vec<(String, State)>>,
+    line_width: &line_numbers::SideBySideLineWidth,
+) -> bool {
+    [Left, Right].iter().any(|side| {
+        lines[*side]
+            .iter()
+            .any(|(line, _)| line_is_too_long(line, line_width[*side]))
+    })
+}
+
 /// Return whether any of the input lines is too long, and a data
 /// structure indicating which are too long. This avoids
 /// calculating the length again later.
@@ -120,7 +225,7 @@ pub fn paint_minus_and_plus_lines_side_by_side<'a>(
     background_color_extends_to_terminal_width: LeftRight<BgShouldFill>,
 ) {
     for (minus_line_index, plus_line_index) in line_alignment {
-        output_buffer.push_str(&paint_left_panel_minus_line(
+        let left_panel_line = paint_left_panel_minus_line(
             minus_line_index,
             &syntax_left_right[Left],
             &diff_left_right[Left],
@@ -131,8 +236,8 @@ pub fn paint_minus_and_plus_lines_side_by_side<'a>(
             line_numbers_data,
             background_color_extends_to_terminal_width[Left],
             config,
-        ));
-        output_buffer.push_str(&paint_right_panel_plus_line(
+        );
+        let right_panel_line = paint_right_panel_plus_line(
             plus_line_index,
             &syntax_left_right[Right],
             &diff_left_right[Right],
@@ -143,14 +248,19 @@ pub fn paint_minus_and_plus_lines_side_by_side<'a>(
             line_numbers_data,
             background_color_extends_to_terminal_width[Right],
             config,
-        ));
-        output_buffer.push('\n');
+        );
+        push_panel_lines(
+            output_buffer,
+            LeftRight::new(left_panel_line, right_panel_line),
+            config,
+        );
     }
 }
 
 #[allow(clippy::too_many_arguments)]
 pub fn paint_zero_lines_side_by_side<'a>(
     raw_line: &str,
+    state: State,
     syntax_style_sections: Vec<LineSegments<'a, SyntectStyle>>,
     diff_style_sections: Vec<LineSegments<'a, Style>>,
     output_buffer: &mut String,
@@ -159,26 +269,59 @@ pub fn paint_zero_lines_side_by_side<'a>(
     painted_prefix: Option<ansi_term::ANSIString>,
     background_color_extends_to_terminal_width: BgShouldFill,
 ) {
-    let states = vec![State::HunkZero];
-
-    let (states, syntax_style_sections, diff_style_sections) = wrap_zero_block(
-        config,
-        raw_line,
-        states,
-        syntax_style_sections,
-        diff_style_sections,
-        line_numbers_data,
-    );
+    let states = vec![state];
+
+    // The line is moved into the wrapped lines of the left panel; the right panel only has its own
+    // wrapped lines if its width differs and the line is too long for one of the panels.
+    let (left, right) = if config.wrap_config.max_lines != 1 {
+        let line_width = match line_numbers_data {
+            Some(line_numbers_data) => available_line_width(config, line_numbers_data),
+            None => LeftRight::new(
+                config.side_by_side_data[Left].width,
+                config.side_by_side_data[Right].width,
+            ),
+        };
+        wrap_zero_block(
+            config,
+            raw_line,
+            states,
+            syntax_style_sections,
+            diff_style_sections,
+            line_width,
+        )
+    } else {
+        ((states, syntax_style_sections, diff_style_sections), None)
+    };
+    let wrapped = LeftRight::new(&left, right.as_ref().unwrap_or(&left));
+    let n_lines = std::cmp::max(wrapped[Left].0.len(), wrapped[Right].0.len());
+    let (empty_line_syntax_sections, empty_line_diff_sections) = (Vec::new(), Vec::new());
 
-    for (line_index, ((syntax_sections, diff_sections), state)) in syntax_style_sections
-        .into_iter()
-        .zip_eq(diff_style_sections.iter())
-        .zip_eq(states.into_iter())
-        .enumerate()
-    {
+    for line_index in 0..n_lines {
+        // The left panel must be painted first since painting the right panel increments the
+        // line numbers, see `paint_line`.
+        let mut panel_lines = LeftRight::<String>::default();
         for panel_side in &[Left, Right] {
-            let (mut panel_line, panel_line_is_empty) = Painter::paint_line(
-                &syntax_sections,
+            let (states, syntax_style_sections, diff_style_sections) = wrapped[*panel_side];
+            // If the line was wrapped into fewer lines in this panel than in the other one,
+            // then this panel is padded with empty lines.
+            let (syntax_sections, diff_sections, state, panel_line_index) =
+                if line_index < states.len() {
+                    (
+                        &syntax_style_sections[line_index],
+                        &diff_style_sections[line_index],
+                        states[line_index].clone(),
+                        Some(line_index),
+                    )
+                } else {
+                    (
+                        &empty_line_syntax_sections,
+                        &empty_line_diff_sections,
+                        State::HunkZeroWrapped,
+                        None,
+                    )
+                };
+            let (mut panel_line, panel_line_is_empty, panel_line_width) = Painter::paint_line(
+                syntax_sections,
                 diff_sections,
                 &state,
                 line_numbers_data,
@@ -189,19 +332,37 @@ pub fn paint_zero_lines_side_by_side<'a>(
             pad_panel_line_to_width(
                 &mut panel_line,
                 panel_line_is_empty,
-                Some(line_index),
-                &diff_style_sections,
+                panel_line_width,
+                panel_line_index,
+                diff_style_sections,
                 &state,
                 *panel_side,
                 background_color_extends_to_terminal_width,
                 config,
             );
-            output_buffer.push_str(&panel_line);
+            panel_lines[*panel_side] = panel_line;
         }
-        output_buffer.push('\n');
+        push_panel_lines(output_buffer, panel_lines, config);
     }
 }
 
+/// Append a line consisting of the two panel lines, in the order given by the configured
+/// side-by-side direction.
+fn push_panel_lines(output_buffer: &mut String, panel_lines: LeftRight<String>, config: &Config) {
+    let [leftmost_panel, rightmost_panel] = config.side_by_side_direction.panels();
+    output_buffer.push_str(&panel_lines[leftmost_panel]);
+    if !config.line_numbers_separator_format.is_empty() {
+        output_buffer.push_str(
+            &config
+                .line_numbers_separator_style
+                .paint(&config.line_numbers_separator_format)
+                .to_string(),
+        );
+    }
+    output_buffer.push_str(&panel_lines[rightmost_panel]);
+    output_buffer.push('\n');
+}
+
 #[allow(clippy::too_many_arguments)]
 fn paint_left_panel_minus_line<'a>(
     line_index: Option<usize>,
@@ -212,7 +373,7 @@ fn paint_left_panel_minus_line<'a>(
     background_color_extends_to_terminal_width: BgShouldFill,
     config: &Config,
 ) -> String {
-    let (mut panel_line, panel_line_is_empty) = paint_minus_or_plus_panel_line(
+    let (mut panel_line, panel_line_is_empty, panel_line_width) = paint_minus_or_plus_panel_line(
         line_index,
         syntax_style_sections,
         diff_style_sections,
@@ -224,6 +385,7 @@ fn paint_left_panel_minus_line<'a>(
     pad_panel_line_to_width(
         &mut panel_line,
         panel_line_is_empty,
+        panel_line_width,
         line_index,
         diff_style_sections,
         state,
@@ -245,7 +407,7 @@ fn paint_right_panel_plus_line<'a>(
     background_color_extends_to_terminal_width: BgShouldFill,
     config: &Config,
 ) -> String {
-    let (mut panel_line, panel_line_is_empty) = paint_minus_or_plus_panel_line(
+    let (mut panel_line, panel_line_is_empty, panel_line_width) = paint_minus_or_plus_panel_line(
         line_index,
         syntax_style_sections,
         diff_style_sections,
@@ -258,6 +420,7 @@ fn paint_right_panel_plus_line<'a>(
     pad_panel_line_to_width(
         &mut panel_line,
         panel_line_is_empty,
+        panel_line_width,
         line_index,
         diff_style_sections,
         state,
@@ -278,16 +441,22 @@ fn get_right_fill_style_for_panel<'a>(
     background_color_extends_to_terminal_width: BgShouldFill,
     config: &Config,
 ) -> (Option<BgFillMethod>, Style) {
-    // If in the the left panel then it must be filled with spaces.
-    let none_or_override = if panel_side == Left {
+    // If in the the leftmost panel then it must be filled with spaces.
+    let is_leftmost_panel = config.side_by_side_direction.is_leftmost(panel_side);
+    let none_or_override = if is_leftmost_panel {
         Some(BgFillMethod::Spaces)
     } else {
         None
     };
+    // A pattern also marks panels without content, in either panel.
+    let pattern_or_override = match config.line_fill_method {
+        BgFillMethod::Pattern(c) => Some(BgFillMethod::Pattern(c)),
+        _ => none_or_override,
+    };
 
     match (line_is_empty, line_index) {
-        (true, _) => (none_or_override, config.null_style),
-        (false, None) => (none_or_override, config.null_style),
+        (true, _) => (pattern_or_override, config.null_style),
+        (false, None) => (pattern_or_override, config.null_style),
         (false, Some(index)) => {
             let (bg_fill_mode, fill_style) =
                 Painter::get_should_right_fill_background_color_and_fill_style(
@@ -299,7 +468,8 @@ fn get_right_fill_style_for_panel<'a>(
 
             match bg_fill_mode {
                 None => (none_or_override, config.null_style),
-                _ if panel_side == Left => (Some(BgFillMethod::Spaces), fill_style),
+                Some(BgFillMethod::Pattern(_)) => (bg_fill_mode, fill_style),
+                _ if is_leftmost_panel => (Some(BgFillMethod::Spaces), fill_style),
                 _ => (bg_fill_mode, fill_style),
             }
         }
@@ -307,7 +477,7 @@ fn get_right_fill_style_for_panel<'a>(
 }
 
 /// Construct half of a minus or plus line under side-by-side mode, i.e. the half line that
-/// goes in one or other panel. Return a tuple `(painted_half_line, is_empty)`.
+/// goes in one or other panel. Return a tuple `(painted_half_line, is_empty, width)`.
 // Suppose the line being displayed is a minus line with a paired plus line. Then both times
 // this function is called, `line_index` will be `Some`. This case proceeds as one would
 // expect: on the first call, we are constructing the left panel line, and we are passed
@@ -336,7 +506,7 @@ fn paint_minus_or_plus_panel_line<'a>(
     line_numbers_data: &mut Option<&mut line_numbers::LineNumbersData>,
     panel_side: PanelSide,
     config: &Config,
-) -> (String, bool) {
+) -> (String, bool, usize) {
     let (empty_line_syntax_sections, empty_line_diff_sections) = (Vec::new(), Vec::new());
 
     let (line_syntax_sections, line_diff_sections, state_for_line_numbers_field) =
@@ -367,7 +537,7 @@ fn paint_minus_or_plus_panel_line<'a>(
         _ => None,
     };
 
-    let (line, line_is_empty) = Painter::paint_line(
+    Painter::paint_line(
         line_syntax_sections,
         line_diff_sections,
         &state_for_line_numbers_field,
@@ -375,51 +545,52 @@ fn paint_minus_or_plus_panel_line<'a>(
         Some(panel_side),
         painted_prefix,
         config,
-    );
-
-    (line, line_is_empty)
+    )
 }
 
-/// Right-fill the background color of a line in a panel. If in the left panel this is always
-/// done with spaces. The right panel can be filled with spaces or using ANSI sequences
-/// instructing the terminal emulator to fill the background color rightwards.
+/// Right-fill the background color of a line in a panel. If in the leftmost panel this is always
+/// done with spaces. The rightmost panel can be filled with spaces or using ANSI sequences
+/// instructing the terminal emulator to fill the background color rightwards. Return the number of
+/// columns filled, i.e. the number of spaces added or the width the ANSI sequence will fill.
+/// `panel_line_width` is the display width of `panel_line`, as returned by `Painter::paint_line`.
 #[allow(clippy::too_many_arguments, clippy::comparison_chain)]
 fn pad_panel_line_to_width<'a>(
     panel_line: &mut String,
     panel_line_is_empty: bool,
+    panel_line_width: usize,
     line_index: Option<usize>,
     diff_style_sections: &[LineSegments<'a, Style>],
     state: &State,
     panel_side: PanelSide,
     background_color_extends_to_terminal_width: BgShouldFill,
     config: &Config,
-) {
+) -> usize {
+    let mut text_width = panel_line_width;
     // Emit empty line marker if the panel line is empty but not empty-by-construction. IOW if the
     // other panel contains a real line, and we are currently emitting an empty counterpart panel
     // to form the other half of the line, then don't emit the empty line marker.
     if panel_line_is_empty && line_index.is_some() {
-        match state {
-            State::HunkMinus(_) => Painter::mark_empty_line(
-                &config.minus_empty_line_marker_style,
-                panel_line,
-                Some(" "),
-            ),
-            State::HunkPlus(_) => Painter::mark_empty_line(
-                &config.plus_empty_line_marker_style,
-                panel_line,
-                Some(" "),
-            ),
-            State::HunkZero => {}
+        let empty_line_marker_style = match state {
+            State::HunkMinus(_) => Some(&config.minus_empty_line_marker_style),
+            State::HunkPlus(_) => Some(&config.plus_empty_line_marker_style),
+            State::HunkZero => None,
             _ => unreachable!(),
         };
+        if let Some(style) = empty_line_marker_style {
+            Painter::mark_empty_line(style, panel_line, Some(" "));
+            text_width += 1;
+        }
     };
 
-    let text_width = ansi::measure_text_width(panel_line);
     let panel_width = config.side_by_side_data[panel_side].width;
 
     if text_width > panel_width {
-        *panel_line =
-            ansi::truncate_str(panel_line, panel_width, &config.truncation_symbol).to_string();
+        // Unless wrapping is disabled, the line is too long even after being wrapped.
+        let truncation_symbol = match &config.wrap_config.overflow_symbol {
+            Some(overflow_symbol) if config.wrap_config.max_lines != 1 => overflow_symbol,
+            _ => &config.truncation_symbol,
+        };
+        *panel_line = ansi::truncate_str(panel_line, panel_width, truncation_symbol).to_string();
     }
 
     let (bg_fill_mode, fill_style) = get_right_fill_style_for_panel(
@@ -432,22 +603,32 @@ fn pad_panel_line_to_width<'a>(
         config,
     );
 
+    let fill_width = panel_width.saturating_sub(text_width);
     match bg_fill_mode {
         Some(BgFillMethod::TryAnsiSequence) => {
-            Painter::right_fill_background_color(panel_line, fill_style)
+            Painter::right_fill_background_color(panel_line, fill_style);
+            fill_width
         }
-        Some(BgFillMethod::Spaces) if text_width >= panel_width => (),
-        Some(BgFillMethod::Spaces) => panel_line.push_str(
-            &fill_style
-                .paint(" ".repeat(panel_width - text_width))
-                .to_string(),
-        ),
-        None => (),
+        Some(BgFillMethod::Spaces) if fill_width == 0 => 0,
+        Some(BgFillMethod::Spaces) => {
+            panel_line.push_str(&fill_style.paint(" ".repeat(fill_width)).to_string());
+            fill_width
+        }
+        Some(BgFillMethod::Pattern(_)) if fill_width == 0 => 0,
+        Some(BgFillMethod::Pattern(c)) => {
+            // A double-width pattern character may leave one column to be filled with a space.
+            let char_width = UnicodeWidthChar::width(c).unwrap_or(1).max(1);
+            let mut fill = c.to_string().repeat(fill_width / char_width);
+            fill.push_str(&" ".repeat(fill_width % char_width));
+            panel_line.push_str(&fill_style.paint(fill).to_string());
+            fill_width
+        }
+        None => 0,
     }
 }
 
 pub mod ansifill {
-    use super::SideBySideData;
+    use super::{SideBySideData, SideBySideDirection};
     use crate::config::Config;
     use crate::paint::BgFillMethod;
 
@@ -479,10 +660,11 @@ pub mod ansifill {
         pub fn sbs_odd_fix(
             width: &crate::cli::Width,
             method: &BgFillMethod,
+            direction: SideBySideDirection,
             sbs_data: SideBySideData,
         ) -> SideBySideData {
             if Self::is_odd_with_ansi(width, method) {
-                Self::adapt_sbs_data(sbs_data)
+                Self::adapt_sbs_data(sbs_data, direction)
             } else {
                 sbs_data
             }
@@ -494,8 +676,12 @@ pub mod ansifill {
             method == &BgFillMethod::TryAnsiSequence
                 && matches!(&width, crate::cli::Width::Fixed(width) if width % 2 == 1)
         }
-        fn adapt_sbs_data(mut sbs_data: SideBySideData) -> SideBySideData {
-            sbs_data[super::Right].width += 1;
+        fn adapt_sbs_data(
+            mut sbs_data: SideBySideData,
+            direction: SideBySideDirection,
+        ) -> SideBySideData {
+            let rightmost_panel = direction.panels()[1];
+            sbs_data[rightmost_panel].width += 1;
             sbs_data
         }
     }
@@ -503,10 +689,101 @@ pub mod ansifill {
 
 #[cfg(test)]
 pub mod tests {
+    use super::{has_any_long_line, has_long_lines, pad_panel_line_to_width, LeftRight};
     use crate::ansi::strip_ansi_codes;
+    use crate::delta::State;
     use crate::features::line_numbers::tests::*;
+    use crate::features::side_by_side::{Left, Right};
+    use crate::paint::BgShouldFill;
     use crate::tests::integration_test_utils::{make_config_from_args, run_delta};
 
+    #[test]
+    fn test_has_any_long_line() {
+        let short = vec![("-a\n".to_string(), State::HunkMinus(None))];
+        let long = vec![
+            ("+a\n".to_string(), State::HunkPlus(None)),
+            ("+abcdef\n".to_string(), State::HunkPlus(None)),
+        ];
+        let line_width = LeftRight::new(4, 4);
+
+        let lines = LeftRight::new(&short, &short);
+        assert!(!has_any_long_line(&lines, &line_width));
+        assert!(!has_long_lines(&lines, &line_width).0);
+
+        let lines = LeftRight::new(&short, &long);
+        assert!(has_any_long_line(&lines, &line_width));
+        assert!(has_long_lines(&lines, &line_width).0);
+    }
+
+    #[test]
+    fn test_pad_panel_line_to_width() {
+        let config = make_config_from_args(&["--side-by-side", "--width", "40"]);
+        let pad = |panel_line: &mut String, panel_side| {
+            let width = strip_ansi_codes(panel_line).len();
+            pad_panel_line_to_width(
+                panel_line,
+                true,
+                width,
+                None,
+                &[],
+                &State::HunkZero,
+                panel_side,
+                BgShouldFill::default(),
+                &config,
+            )
+        };
+
+        // The leftmost panel is always filled with spaces.
+        let mut panel_line = "abc".to_string();
+        let fill_width = pad(&mut panel_line, Left);
+        assert_eq!(fill_width, config.side_by_side_data[Left].width - 3);
+        assert_eq!(
+            strip_ansi_codes(&panel_line),
+            format!("abc{}", " ".repeat(fill_width))
+        );
+
+        // An empty rightmost panel is not filled.
+        let mut panel_line = "abc".to_string();
+        assert_eq!(pad(&mut panel_line, Right), 0);
+        assert_eq!(panel_line, "abc");
+    }
+
+    #[test]
+    fn test_pad_panel_line_to_width_with_pattern() {
+        for (pattern, char_width) in &[('·', 1), ('全', 2)] {
+            let method = format!("pattern:{}", pattern);
+            let config = make_config_from_args(&[
+                "--side-by-side",
+                "--width",
+                "40",
+                "--line-fill-method",
+                &method,
+            ]);
+            // Unlike other fill methods, a pattern also fills an empty rightmost panel.
+            let mut panel_line = "abc".to_string();
+            let fill_width = pad_panel_line_to_width(
+                &mut panel_line,
+                true,
+                3,
+                None,
+                &[],
+                &State::HunkZero,
+                Right,
+                BgShouldFill::default(),
+                &config,
+            );
+            assert_eq!(fill_width, config.side_by_side_data[Right].width - 3);
+            assert_eq!(
+                strip_ansi_codes(&panel_line),
+                format!(
+                    "abc{}{}",
+                    pattern.to_string().repeat(fill_width / char_width),
+                    " ".repeat(fill_width % char_width)
+                )
+            );
+        }
+    }
+
     #[test]
     fn test_two_minus_lines() {
         let config = make_config_from_args(&["--side-by-side", "--width", "40"]);
@@ -517,6 +794,48 @@ pub mod tests {
         assert_eq!("│ 2  │b = 23456     │    │", strip_ansi_codes(line_2));
     }
 
+    #[test]
+    fn test_line_numbers_separator_format() {
+        let config = make_config_from_args(&[
+            "--side-by-side",
+            "--width",
+            "40",
+            "--line-numbers-separator-format",
+            "┃",
+            "--line-numbers-right-format",
+            "{np:^4}│",
+        ]);
+        assert_eq!(config.side_by_side_data[Left].width, 20);
+        assert_eq!(config.side_by_side_data[Right].width, 19);
+        let output = run_delta(TWO_MINUS_LINES_DIFF, &config);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        let (line_1, line_2) = (lines.next().unwrap(), lines.next().unwrap());
+        assert_eq!("│ 1  │a = 1         ┃    │", strip_ansi_codes(line_1));
+        assert_eq!("│ 2  │b = 23456     ┃    │", strip_ansi_codes(line_2));
+    }
+
+    #[test]
+    fn test_column_separator_styles() {
+        let config = make_config_from_args(&[
+            "--side-by-side",
+            "--width",
+            "40",
+            "--side-by-side-column-separator-style",
+            "red",
+            "--separator-between-panels",
+            "blue",
+        ]);
+        let output = run_delta(TWO_MINUS_LINES_DIFF, &config);
+        let line_1 = output.lines().nth(crate::config::HEADER_LEN).unwrap();
+        assert_eq!("│ 1  │a = 1         │    │", strip_ansi_codes(line_1));
+        // Consecutive painted strings are joined without reset, so only look for the color codes.
+        let red_separator = format!("{}│", ansi_term::Color::Red.prefix());
+        let blue_separator = format!("{}│", ansi_term::Color::Blue.prefix());
+        assert_eq!(line_1.matches(&red_separator).count(), 3);
+        assert_eq!(line_1.matches(&blue_separator).count(), 1);
+        assert!(line_1.find(&blue_separator) > line_1.find("a = 1"));
+    }
+
     #[test]
     fn test_two_minus_lines_truncated() {
         let mut config = make_config_from_args(&[
@@ -535,6 +854,36 @@ pub mod tests {
         assert_eq!("│ 2  │b = 234>│    │", strip_ansi_codes(line_2));
     }
 
+    #[test]
+    fn test_min_panel_width() {
+        let config = make_config_from_args(&["--side-by-side", "--width", "38"]);
+        assert!(config.side_by_side);
+        let config =
+            make_config_from_args(&["--side-by-side", "--width", "38", "--min-panel-width", "20"]);
+        assert!(!config.side_by_side);
+        let config =
+            make_config_from_args(&["--side-by-side", "--width", "38", "--min-panel-width", "19"]);
+        assert!(config.side_by_side);
+    }
+
+    #[test]
+    fn test_two_minus_lines_right_to_left() {
+        let config = make_config_from_args(&[
+            "--side-by-side",
+            "--side-by-side-direction",
+            "rtl",
+            "--width",
+            "40",
+            "--line-fill-method=spaces",
+        ]);
+        let output = run_delta(TWO_MINUS_LINES_DIFF, &config);
+        let mut lines = output.lines().skip(crate::config::HEADER_LEN);
+        let (line_1, line_2) = (lines.next().unwrap(), lines.next().unwrap());
+        let sac = strip_ansi_codes; // alias to help with `cargo fmt`-ing:
+        assert_eq!("│    │              │ 1  │a = 1         ", sac(line_1));
+        assert_eq!("│    │              │ 2  │b = 23456     ", sac(line_2));
+    }
+
     #[test]
     fn test_two_plus_lines() {
         let config = make_config_from_args(&[
@@ -597,4 +946,78 @@ pub mod tests {
         assert_eq!("│ 1  │a = 1         │ 1  │a = 1", lnu());
         assert_eq!("│ 2  │b = 2         │ 2  │bb = 2        ", lnu());
     }
+
+    #[test]
+    fn test_line_numbers_format_wider_than_panel() {
+        let config = make_config_from_args(&[
+            "--side-by-side",
+            "--width",
+            "40",
+            "--line-numbers-left-format",
+            "│{nm:^4}│ a format wider than the panel │",
+        ]);
+        let output = run_delta(ONE_MINUS_ONE_PLUS_LINE_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let lines: Vec<&str> = output.lines().skip(crate::config::HEADER_LEN).collect();
+        // The left panel only has room for (part of) its line numbers, but the right panel and
+        // the lines are still displayed.
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("│ 1  │ a format"));
+        assert!(lines[0].contains(" 1  │a = 1"));
+        assert!(lines[1].contains(" 2  │bb = 2"));
+    }
+
+    #[test]
+    fn test_available_line_width_warning() {
+        use crate::features::line_numbers::LineNumbersData;
+        use crate::features::side_by_side::{ansifill, available_line_width_and_warning};
+
+        let line_width_and_warning = |args: &[&str]| {
+            let config = make_config_from_args(args);
+            let mut data = LineNumbersData::from_format_strings(
+                &config.line_numbers_format,
+                ansifill::UseFullPanelWidth(false),
+                config.side_by_side_direction,
+            );
+            data.initialize_hunk(&[(1, 1), (1, 1)], "a".into());
+            available_line_width_and_warning(&config, &data)
+        };
+
+        let (line_width, warning) = line_width_and_warning(&["--side-by-side", "--width", "40"]);
+        assert_eq!(line_width, LeftRight::new(15, 15));
+        assert_eq!(warning, None);
+
+        // The width is not raised above what is left of the panel, so that lines do not overflow.
+        let (line_width, warning) = line_width_and_warning(&[
+            "--side-by-side",
+            "--width",
+            "40",
+            "--line-numbers-left-format",
+            "│{nm:^4}│ a format wider than the panel │",
+        ]);
+        assert_eq!(line_width, LeftRight::new(0, 15));
+        assert_eq!(
+            warning.unwrap(),
+            "delta: the left panel is 20 columns wide (see --width), of which \
+             --line-numbers-left-format uses 38, leaving only 0 columns for its lines."
+        );
+
+        let (line_width, warning) = line_width_and_warning(&[
+            "--side-by-side",
+            "--width",
+            "40",
+            "--keep-plus-minus-markers",
+            "--syntax-line-range",
+            "1-2",
+            "--line-numbers-left-format",
+            "{nm:^4} old line │",
+        ]);
+        assert_eq!(line_width, LeftRight::new(3, 13));
+        assert_eq!(
+            warning.unwrap(),
+            "delta: the left panel is 20 columns wide (see --width), of which \
+             --line-numbers-left-format uses 15 and --keep-plus-minus-markers uses 1 and the \
+             --syntax-line-range marker column uses 1, leaving only 3 columns for its lines."
+        );
+    }
 }
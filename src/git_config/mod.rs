@@ -5,7 +5,6 @@ pub use git_config_entry::{GitConfigEntry, GitRemoteRepo};
 use regex::Regex;
 use std::collections::HashMap;
 use std::env;
-#[cfg(test)]
 use std::path::Path;
 
 use crate::fatal;
@@ -45,6 +44,26 @@ impl GitConfig {
         }
     }
 
+    /// Read git config from the file at `path` only, as requested by --config.
+    pub fn from_file(path: &Path, repo: Option<git2::Repository>) -> Self {
+        if !path.is_file() {
+            fatal(format!("Config file not found: {}", path.display()));
+        }
+        let config = git2::Config::open(path).unwrap_or_else(|err| {
+            fatal(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                err
+            ));
+        });
+        Self {
+            config,
+            config_from_env_var: parse_config_from_env_var(),
+            repo,
+            enabled: true,
+        }
+    }
+
     #[cfg(test)]
     pub fn from_path(path: &Path, honor_env_var: bool) -> Self {
         Self {
@@ -69,6 +88,25 @@ impl GitConfig {
             None
         }
     }
+
+    /// Return all values of the multi-valued `key`, in the order in which they are set.
+    pub fn get_all(&self, key: &str) -> Vec<String> {
+        if !self.enabled {
+            return Vec::new();
+        }
+        if let Some(val) = self.config_from_env_var.get(key) {
+            return vec![val.to_string()];
+        }
+        let mut values = Vec::new();
+        if let Ok(entries) = self.config.multivar(key, None) {
+            for entry in &entries {
+                if let Some(value) = entry.ok().and_then(|entry| entry.value().map(String::from)) {
+                    values.push(value);
+                }
+            }
+        }
+        values
+    }
 }
 
 fn parse_config_from_env_var() -> HashMap<String, String> {
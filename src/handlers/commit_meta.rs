@@ -1,9 +1,26 @@
 use std::borrow::Cow;
 
+use chrono::{DateTime, Local, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+
 use super::draw;
+use crate::config::Config;
 use crate::delta::{State, StateMachine};
 use crate::features;
 
+/// The timezone in which the Date: line of a commit header is displayed. `Original` keeps the
+/// timezone recorded in the commit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommitTimestampTimezone {
+    Original,
+    Local,
+    Utc,
+}
+
+/// The format of git's default date output, e.g. "Thu May 14 11:13:17 2020 -0400".
+const GIT_DEFAULT_DATE_FORMAT: &str = "%a %b %-d %H:%M:%S %Y %z";
+
 impl<'a> StateMachine<'a> {
     #[inline]
     fn test_commit_meta_header_line(&self) -> bool {
@@ -15,7 +32,7 @@ impl<'a> StateMachine<'a> {
             return Ok(false);
         }
         let mut handled_line = false;
-        self.painter.paint_buffered_minus_and_plus_lines();
+        self.painter.paint_buffered_lines_at_hunk_end();
         self.state = State::CommitMeta;
         if self.should_handle() {
             self.painter.emit()?;
@@ -25,6 +42,28 @@ impl<'a> StateMachine<'a> {
         Ok(handled_line)
     }
 
+    #[inline]
+    fn test_commit_meta_date_line(&self) -> bool {
+        self.state == State::CommitMeta
+            && (self.config.commit_timestamp_format.is_some()
+                || self.config.commit_timestamp_timezone != CommitTimestampTimezone::Original)
+    }
+
+    /// Reformat the Date: line of a commit header according to --commit-timestamp-format and
+    /// --commit-timestamp-timezone.
+    pub fn handle_commit_meta_date_line(&mut self) -> std::io::Result<bool> {
+        if !self.test_commit_meta_date_line() {
+            return Ok(false);
+        }
+        let mut handled_line = false;
+        if let Some(line) = reformat_commit_date_line(&self.line, self.config) {
+            self.painter.emit()?;
+            writeln!(self.painter.writer, "{}", line)?;
+            handled_line = true
+        }
+        Ok(handled_line)
+    }
+
     fn _handle_commit_meta_header_line(&mut self) -> std::io::Result<()> {
         if self.config.commit_style.is_omitted {
             return Ok(());
@@ -57,3 +96,83 @@ impl<'a> StateMachine<'a> {
         Ok(())
     }
 }
+
+lazy_static! {
+    static ref COMMIT_DATE_LINE_REGEX: Regex =
+        Regex::new(r"^((?:Author|Commit)?Date:)(\s+)(.+)$").unwrap();
+}
+
+/// Return `line` with its date reformatted, if it is a Date: line (or, as output by
+/// `--pretty=fuller`, an AuthorDate: or CommitDate: line) holding a date in git's default format.
+fn reformat_commit_date_line(line: &str, config: &Config) -> Option<String> {
+    let caps = COMMIT_DATE_LINE_REGEX.captures(line)?;
+    let date = DateTime::parse_from_str(&caps[3], GIT_DEFAULT_DATE_FORMAT).ok()?;
+    let format = config
+        .commit_timestamp_format
+        .as_deref()
+        .unwrap_or(GIT_DEFAULT_DATE_FORMAT);
+    let date = match config.commit_timestamp_timezone {
+        CommitTimestampTimezone::Original => date.format(format).to_string(),
+        CommitTimestampTimezone::Local => date.with_timezone(&Local).format(format).to_string(),
+        CommitTimestampTimezone::Utc => date.with_timezone(&Utc).format(format).to_string(),
+    };
+    Some(format!("{}{}{}", &caps[1], &caps[2], date))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::integration_test_utils;
+
+    #[test]
+    fn test_commit_timestamp_format() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--commit-timestamp-format",
+            "%Y-%m-%d %H:%M",
+        ]);
+        let output = integration_test_utils::run_delta(GIT_LOG, &config);
+        assert!(output.contains("\nDate:   2020-05-14 11:13\n"));
+        assert!(output.contains("\n    Date: not a date line\n"));
+    }
+
+    #[test]
+    fn test_commit_timestamp_timezone() {
+        let config =
+            integration_test_utils::make_config_from_args(&["--commit-timestamp-timezone", "utc"]);
+        let output = integration_test_utils::run_delta(GIT_LOG, &config);
+        assert!(output.contains("\nDate:   Thu May 14 15:13:17 2020 +0000\n"));
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--commit-timestamp-format",
+            "%Y-%m-%d %H:%M %z",
+            "--commit-timestamp-timezone",
+            "original",
+        ]);
+        let output = integration_test_utils::run_delta(GIT_LOG, &config);
+        assert!(output.contains("\nDate:   2020-05-14 11:13 -0400\n"));
+    }
+
+    #[test]
+    fn test_commit_timestamp_unchanged_by_default() {
+        let config = integration_test_utils::make_config_from_args(&[]);
+        let output = integration_test_utils::run_delta(GIT_LOG, &config);
+        assert!(output.contains("\nDate:   Thu May 14 11:13:17 2020 -0400\n"));
+    }
+
+    const GIT_LOG: &str = "\
+commit 94907c0f136f46dc46ffae2dc92dca9af7eb7c2e
+Author: Dan Davison <dandavison7@gmail.com>
+Date:   Thu May 14 11:13:17 2020 -0400
+
+    rustfmt
+
+    Date: not a date line
+
+diff --git a/a.txt b/a.txt
+index 7898192..6178079 100644
+--- a/a.txt
++++ b/a.txt
+@@ -1 +1 @@
+-a
++b
+";
+}
@@ -1,6 +1,7 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::config::Config;
 use crate::delta::{State, StateMachine};
 
 impl<'a> StateMachine<'a> {
@@ -10,10 +11,24 @@ impl<'a> StateMachine<'a> {
             && self.line.starts_with(' ')
     }
 
+    /// Is this a diff stat file entry which is buffered, to be re-rendered by
+    /// `emit_diff_stat_entries` once all entries of the diff stat have been seen?
+    pub fn test_buffered_diff_stat_line(&self) -> bool {
+        self.config.file_stat_bar_style != DiffStatBarStyle::Git
+            && self.test_diff_stat_line()
+            && DiffStatEntry::parse(&self.line).is_some()
+    }
+
     pub fn handle_diff_stat_line(&mut self) -> std::io::Result<bool> {
         if !self.test_diff_stat_line() {
             return Ok(false);
         }
+        if self.config.file_stat_bar_style != DiffStatBarStyle::Git {
+            if let Some(entry) = DiffStatEntry::parse(&self.line) {
+                self.diff_stat_entries.push(entry);
+                return Ok(true);
+            }
+        }
         let mut handled_line = false;
         if self.config.relative_paths {
             if let Some(cwd) = self.config.cwd_relative_to_repo_root.as_deref() {
@@ -30,6 +45,130 @@ impl<'a> StateMachine<'a> {
         }
         Ok(handled_line)
     }
+
+    /// Write the buffered diff stat file entries, if any.
+    pub fn emit_diff_stat_entries(&mut self) -> std::io::Result<()> {
+        if self.diff_stat_entries.is_empty() {
+            return Ok(());
+        }
+        self.painter.emit()?;
+        for line in format_diff_stat_entries(&self.diff_stat_entries, self.config) {
+            writeln!(self.painter.writer, "{}", line)?;
+        }
+        self.diff_stat_entries.clear();
+        Ok(())
+    }
+}
+
+/// The characters used by delta to draw the bar of a diff stat file entry. `Git` leaves the
+/// entries as they were output by git.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiffStatBarStyle {
+    Git,
+    PlusMinus,
+    Line,
+    Shade,
+}
+
+impl DiffStatBarStyle {
+    /// The characters representing insertions and deletions.
+    fn chars(self) -> (&'static str, &'static str) {
+        match self {
+            DiffStatBarStyle::Git | DiffStatBarStyle::PlusMinus => ("+", "-"),
+            DiffStatBarStyle::Line => ("─", "─"),
+            DiffStatBarStyle::Shade => ("▒", "▒"),
+        }
+    }
+}
+
+/// A file entry of a diff stat, such as " src/delta.rs  | 14 ++++++++++----".
+#[derive(Debug, PartialEq)]
+pub struct DiffStatEntry {
+    path: String,
+    changes: usize,
+    insertions: usize,
+}
+
+lazy_static! {
+    static ref DIFF_STAT_ENTRY_REGEX: Regex =
+        Regex::new(r"^ (.*[^ ]) +\| +([0-9]+) (\+*)(-*)$").unwrap();
+}
+
+impl DiffStatEntry {
+    fn parse(line: &str) -> Option<Self> {
+        let caps = DIFF_STAT_ENTRY_REGEX.captures(line)?;
+        let changes = caps[2].parse::<usize>().ok()?;
+        // git scales the bar, so the number of insertions is only known up to rounding.
+        let (plus_len, minus_len) = (caps[3].len(), caps[4].len());
+        let insertions = if plus_len + minus_len == 0 {
+            0
+        } else {
+            (changes * plus_len + (plus_len + minus_len) / 2) / (plus_len + minus_len)
+        };
+        Some(Self {
+            path: caps[1].to_string(),
+            changes,
+            insertions,
+        })
+    }
+}
+
+/// Render diff stat file entries with a file name column of width --file-stat-width and a bar of
+/// at most --file-stat-bar-width characters. Bars are scaled so that the entry with the most
+/// changes fills the whole bar width.
+fn format_diff_stat_entries(entries: &[DiffStatEntry], config: &Config) -> Vec<String> {
+    let max_changes = entries.iter().map(|entry| entry.changes).max().unwrap_or(0);
+    let changes_width = max_changes.to_string().len();
+    let (plus_char, minus_char) = config.file_stat_bar_style.chars();
+    entries
+        .iter()
+        .map(|entry| {
+            let bar_len = if max_changes <= config.file_stat_bar_width {
+                entry.changes
+            } else {
+                let scaled =
+                    (entry.changes * config.file_stat_bar_width + max_changes / 2) / max_changes;
+                if entry.changes > 0 {
+                    scaled.max(1)
+                } else {
+                    0
+                }
+            };
+            let plus_len = if entry.changes == 0 {
+                0
+            } else {
+                (bar_len * entry.insertions + entry.changes / 2) / entry.changes
+            };
+            let path = match config.cwd_relative_to_repo_root.as_deref() {
+                Some(cwd) if config.relative_paths => pathdiff::diff_paths(&entry.path, cwd)
+                    .and_then(|path| path.to_str().map(String::from))
+                    .unwrap_or_else(|| entry.path.clone()),
+                _ => entry.path.clone(),
+            };
+            format!(
+                " {:<path_width$} | {:>changes_width$} {}{}",
+                truncate_path(&path, config.file_stat_width),
+                entry.changes,
+                config.plus_style.paint(plus_char.repeat(plus_len)),
+                config
+                    .minus_style
+                    .paint(minus_char.repeat(bar_len - plus_len)),
+                path_width = config.file_stat_width,
+                changes_width = changes_width,
+            )
+        })
+        .collect()
+}
+
+/// Shorten `path` to `width` characters by replacing its start with "...", as git does.
+fn truncate_path(path: &str, width: usize) -> String {
+    let len = path.chars().count();
+    if len <= width || width <= 3 {
+        path.to_string()
+    } else {
+        let tail: String = path.chars().skip(len - (width - 3)).collect();
+        format!("...{}", tail)
+    }
 }
 
 // A regex to capture the path, and the content from the pipe onwards, in lines
@@ -61,6 +200,8 @@ pub fn relativize_path_in_diff_stat_line(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ansi::strip_ansi_codes;
+    use crate::tests::integration_test_utils;
 
     #[test]
     fn test_diff_stat_line_regex_1() {
@@ -80,6 +221,60 @@ mod tests {
         assert_eq!(caps.get(2).unwrap().as_str(), "|  2 ++");
     }
 
+    #[test]
+    fn test_diff_stat_entry_parse() {
+        assert_eq!(
+            DiffStatEntry::parse(" src/delta.rs  | 14 ++++++++++----"),
+            Some(DiffStatEntry {
+                path: "src/delta.rs".to_string(),
+                changes: 14,
+                insertions: 10,
+            })
+        );
+        assert_eq!(
+            DiffStatEntry::parse(" 2 files changed, 7 insertions(+)"),
+            None
+        );
+        assert_eq!(DiffStatEntry::parse(" a.png | Bin 0 -> 12 bytes"), None);
+    }
+
+    #[test]
+    fn test_file_stat_bar_style() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--file-stat-bar-style",
+            "plus-minus",
+            "--file-stat-width",
+            "10",
+            "--file-stat-bar-width",
+            "5",
+        ]);
+        let output = integration_test_utils::run_delta(DIFF_STAT, &config);
+        let output = strip_ansi_codes(&output);
+        let mut lines = output.lines();
+        assert_eq!(lines.next().unwrap(), " src/a.rs   | 10 +++--");
+        assert_eq!(lines.next().unwrap(), " b.rs       |  2 +");
+        assert_eq!(
+            lines.next().unwrap(),
+            " 2 files changed, 7 insertions(+), 5 deletions(-)"
+        );
+
+        let config = integration_test_utils::make_config_from_args(&[]);
+        let output = integration_test_utils::run_delta(DIFF_STAT, &config);
+        assert_eq!(strip_ansi_codes(&output), DIFF_STAT);
+    }
+
+    #[test]
+    fn test_truncate_path() {
+        assert_eq!(truncate_path("src/a.rs", 8), "src/a.rs");
+        assert_eq!(truncate_path("src/a.rs", 7), "...a.rs");
+    }
+
+    const DIFF_STAT: &str = "\
+ src/a.rs | 10 ++++++----
+ b.rs     |  2 +-
+ 2 files changed, 7 insertions(+), 5 deletions(-)
+";
+
     #[test]
     fn test_relative_path() {
         for (path, cwd_relative_to_repo_root, expected) in &[
@@ -1,4 +1,3 @@
-use std::borrow::Cow;
 use std::path::Path;
 
 use unicode_segmentation::UnicodeSegmentation;
@@ -8,6 +7,7 @@ use crate::config::Config;
 use crate::delta::{Source, State, StateMachine};
 use crate::features;
 use crate::paint::Painter;
+use crate::style::Style;
 
 // https://git-scm.com/docs/git-config#Documentation/git-config.txt-diffmnemonicPrefix
 const DIFF_PREFIXES: [&str; 6] = ["a/", "b/", "c/", "i/", "o/", "w/"];
@@ -119,6 +119,13 @@ impl<'a> StateMachine<'a> {
             .set_syntax(get_file_extension_from_file_meta_line_file_path(
                 &self.plus_file,
             ));
+        self.painter.syntax_disabled =
+            self.config
+                .is_syntax_disabled_for(if self.plus_file == "/dev/null" {
+                    &self.minus_file
+                } else {
+                    &self.plus_file
+                });
         self.current_file_pair = Some((self.minus_file.clone(), self.plus_file.clone()));
 
         // In color_only mode, raw_line's structure shouldn't be changed.
@@ -153,8 +160,14 @@ impl<'a> StateMachine<'a> {
             &self.plus_file_event,
             self.config,
         );
+        let file_path = if self.plus_file == "/dev/null" {
+            &self.minus_file
+        } else {
+            &self.plus_file
+        };
+        let file_style = *self.config.get_file_style(file_path);
         // FIXME: no support for 'raw'
-        write_generic_file_meta_header_line(&line, &line, &mut self.painter, self.config)
+        write_file_meta_header_line(&line, &line, &mut self.painter, file_style, self.config)
     }
 }
 
@@ -164,15 +177,26 @@ pub fn write_generic_file_meta_header_line(
     raw_line: &str,
     painter: &mut Painter,
     config: &Config,
+) -> std::io::Result<()> {
+    write_file_meta_header_line(line, raw_line, painter, config.file_style, config)
+}
+
+/// Write `line` with FileMeta styling, using `file_style` instead of the configured file-style.
+pub fn write_file_meta_header_line(
+    line: &str,
+    raw_line: &str,
+    painter: &mut Painter,
+    file_style: Style,
+    config: &Config,
 ) -> std::io::Result<()> {
     // If file_style is "omit", we'll skip the process and print nothing.
     // However in the case of color_only mode,
     // we won't skip because we can't change raw_line structure.
-    if config.file_style.is_omitted && !config.color_only {
+    if file_style.is_omitted && !config.color_only {
         return Ok(());
     }
     let (mut draw_fn, pad, decoration_ansi_term_style) =
-        draw::get_draw_function(config.file_style.decoration_style);
+        draw::get_draw_function(file_style.decoration_style);
     // Prints the new line below file-meta-line.
     // However in the case of color_only mode,
     // we won't print it because we can't change raw_line structure.
@@ -184,7 +208,7 @@ pub fn write_generic_file_meta_header_line(
         &format!("{}{}", line, if pad { " " } else { "" }),
         &format!("{}{}", raw_line, if pad { " " } else { "" }),
         &config.decorations_width,
-        config.file_style,
+        file_style,
         decoration_ansi_term_style,
     )?;
     Ok(())
@@ -194,7 +218,7 @@ pub fn write_generic_file_meta_header_line(
 /// Given input like
 /// "--- one.rs	2019-11-20 06:16:08.000000000 +0100"
 /// Return "rs"
-fn get_file_extension_from_marker_line(line: &str) -> Option<&str> {
+pub fn get_file_extension_from_marker_line(line: &str) -> Option<&str> {
     line.split('\t')
         .next()
         .and_then(|column| column.split(' ').nth(1))
@@ -305,7 +329,11 @@ pub fn get_file_change_description_from_file_paths(
     config: &Config,
 ) -> String {
     if comparing {
-        format!("comparing: {} ⟶   {}", minus_file, plus_file)
+        format!(
+            "comparing: {} ⟶   {}",
+            config.display_file_path(minus_file),
+            config.display_file_path(plus_file)
+        )
     } else {
         let format_label = |label: &str| {
             if !label.is_empty() {
@@ -314,11 +342,13 @@ pub fn get_file_change_description_from_file_paths(
                 "".to_string()
             }
         };
+        // Hyperlinks point to the original path; only the displayed text is transformed.
         let format_file = |file| {
+            let text = config.display_file_path(file);
             if config.hyperlinks {
-                features::hyperlinks::format_osc8_file_hyperlink(file, None, file, config)
+                features::hyperlinks::format_osc8_file_hyperlink(file, None, &text, config)
             } else {
-                Cow::from(file)
+                text
             }
         };
         match (minus_file, plus_file, minus_file_event, plus_file_event) {
@@ -330,9 +360,14 @@ pub fn get_file_change_description_from_file_paths(
             ) if minus_file == plus_file => match (old_mode.as_str(), new_mode.as_str()) {
                 // 100755 for executable and 100644 for non-executable are the only file modes Git records.
                 // https://medium.com/@tahteche/how-git-treats-changes-in-file-permissions-f71874ca239d
-                ("100644", "100755") => format!("{}: mode +x", plus_file),
-                ("100755", "100644") => format!("{}: mode -x", plus_file),
-                _ => format!("{}: {} ⟶   {}", plus_file, old_mode, new_mode),
+                ("100644", "100755") => format!("{}: mode +x", config.display_file_path(plus_file)),
+                ("100755", "100644") => format!("{}: mode -x", config.display_file_path(plus_file)),
+                _ => format!(
+                    "{}: {} ⟶   {}",
+                    config.display_file_path(plus_file),
+                    old_mode,
+                    new_mode
+                ),
             },
             (minus_file, plus_file, _, _) if minus_file == plus_file => format!(
                 "{}{}",
@@ -11,7 +11,7 @@ impl<'a> StateMachine<'a> {
         if !self.test_file_meta_diff_line() {
             return Ok(false);
         }
-        self.painter.paint_buffered_minus_and_plus_lines();
+        self.painter.paint_buffered_lines_at_hunk_end();
         self.state = State::FileMeta;
         self.handled_file_meta_header_line_file_pair = None;
         self.diff_line = self.line.clone();
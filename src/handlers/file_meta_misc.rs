@@ -1,4 +1,5 @@
 use crate::delta::{Source, State, StateMachine};
+use crate::handlers::file_meta::write_file_meta_header_line;
 
 impl<'a> StateMachine<'a> {
     #[inline]
@@ -11,6 +12,33 @@ impl<'a> StateMachine<'a> {
         if !self.test_file_meta_misc_cases() {
             return Ok(false);
         }
+        if self.line.starts_with("Binary files ") {
+            return self.handle_binary_files_line();
+        }
         self.handle_additional_cases(State::FileMeta)
     }
+
+    /// Like `handle_additional_cases`, but the notice is written in binary-diff-style, or not at
+    /// all with --suppress-diff-binary-notice.
+    fn handle_binary_files_line(&mut self) -> std::io::Result<bool> {
+        self.painter.paint_buffered_lines_at_hunk_end();
+        self.state = State::FileMeta;
+        if !self.should_handle() {
+            return Ok(false);
+        }
+        if self.config.suppress_diff_binary_notice && !self.config.color_only {
+            return Ok(true);
+        }
+        self.painter.emit()?;
+        write_file_meta_header_line(
+            &self.line,
+            &self.raw_line,
+            &mut self.painter,
+            self.config
+                .binary_diff_style
+                .unwrap_or(self.config.file_style),
+            self.config,
+        )?;
+        Ok(true)
+    }
 }
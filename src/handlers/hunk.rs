@@ -1,5 +1,7 @@
+use std::borrow::Cow;
+
 use crate::cli;
-use crate::delta::{State, StateMachine};
+use crate::delta::{self, State, StateMachine};
 use crate::style;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -30,6 +32,9 @@ impl<'a> StateMachine<'a> {
         if !self.test_hunk_line() {
             return Ok(false);
         }
+        if self.config.suppress_empty_hunk_context && self.hold_back_hunk_line()? {
+            return Ok(true);
+        }
         // Don't let the line buffers become arbitrarily large -- if we
         // were to allow that, then for a large deleted/added file we
         // would process the entire file before painting anything.
@@ -41,54 +46,76 @@ impl<'a> StateMachine<'a> {
         if let State::HunkHeader(line, raw_line) = &self.state.clone() {
             self.emit_hunk_header_line(line, raw_line)?;
         }
+        if let Some(annotation) = self.hunk_annotation.as_mut() {
+            annotation.count_line(&self.line);
+        }
+        let line = delta::make_line_ending_visible(&self.line, self.line_ending, self.config);
         self.state = match self.line.chars().next() {
             Some('-') => {
                 if let State::HunkPlus(_) = self.state {
                     self.painter.paint_buffered_minus_and_plus_lines();
                 }
-                let state = match self.config.inspect_raw_lines {
-                    cli::InspectRawLines::True
-                        if style::line_has_style_other_than(
-                            &self.raw_line,
-                            [*style::GIT_DEFAULT_MINUS_STYLE, self.config.git_minus_style].iter(),
-                        ) =>
-                    {
-                        State::HunkMinus(Some(self.painter.prepare_raw_line(&self.raw_line)))
-                    }
-                    _ => State::HunkMinus(None),
+                self.painter.paint_lines_before_change();
+                let (line, state) = if let Some((line, raw_line)) = self.get_redacted_line() {
+                    (Cow::from(line), State::HunkMinus(Some(raw_line)))
+                } else {
+                    let state = match self.config.inspect_raw_lines {
+                        cli::InspectRawLines::True
+                            if style::line_has_style_other_than(
+                                &self.raw_line,
+                                [*style::GIT_DEFAULT_MINUS_STYLE, self.config.git_minus_style]
+                                    .iter(),
+                            ) =>
+                        {
+                            State::HunkMinus(Some(self.painter.prepare_raw_line(&self.raw_line)))
+                        }
+                        _ => State::HunkMinus(None),
+                    };
+                    (line, state)
                 };
                 self.painter
                     .minus_lines
-                    .push((self.painter.prepare(&self.line), state.clone()));
+                    .push((self.painter.prepare(&line), state.clone()));
                 state
             }
             Some('+') => {
-                let state = match self.config.inspect_raw_lines {
-                    cli::InspectRawLines::True
-                        if style::line_has_style_other_than(
-                            &self.raw_line,
-                            [*style::GIT_DEFAULT_PLUS_STYLE, self.config.git_plus_style].iter(),
-                        ) =>
-                    {
-                        State::HunkPlus(Some(self.painter.prepare_raw_line(&self.raw_line)))
-                    }
-                    _ => State::HunkPlus(None),
+                self.painter.paint_lines_before_change();
+                let (line, state) = if let Some((line, raw_line)) = self.get_redacted_line() {
+                    (Cow::from(line), State::HunkPlus(Some(raw_line)))
+                } else {
+                    let state = match self.config.inspect_raw_lines {
+                        cli::InspectRawLines::True
+                            if style::line_has_style_other_than(
+                                &self.raw_line,
+                                [*style::GIT_DEFAULT_PLUS_STYLE, self.config.git_plus_style].iter(),
+                            ) =>
+                        {
+                            State::HunkPlus(Some(self.painter.prepare_raw_line(&self.raw_line)))
+                        }
+                        _ => State::HunkPlus(None),
+                    };
+                    (line, state)
                 };
                 self.painter
                     .plus_lines
-                    .push((self.painter.prepare(&self.line), state.clone()));
+                    .push((self.painter.prepare(&line), state.clone()));
                 state
             }
             Some(' ') => {
                 self.painter.paint_buffered_minus_and_plus_lines();
-                self.painter.paint_zero_line(&self.line);
+                self.painter.paint_or_buffer_zero_line(&line);
                 State::HunkZero
             }
             _ => {
                 // The first character here could be e.g. '\' from '\ No newline at end of file'. This
                 // is not a hunk line, but the parser does not have a more accurate state corresponding
                 // to this.
-                self.painter.paint_buffered_minus_and_plus_lines();
+                if self.state == State::HunkZero {
+                    // An unchanged line without a newline at the end is the last line of the hunk.
+                    self.painter.paint_buffered_lines_at_hunk_end();
+                } else {
+                    self.painter.paint_buffered_minus_and_plus_lines();
+                }
                 self.painter
                     .output_buffer
                     .push_str(&self.painter.expand_tabs(self.raw_line.graphemes(true)));
@@ -99,4 +126,74 @@ impl<'a> StateMachine<'a> {
         self.painter.emit()?;
         Ok(true)
     }
+
+    /// With --suppress-empty-hunk-context, hold back the hunk header and the lines of the hunk
+    /// until its first changed line, and return whether the current line was held back. Held back
+    /// lines of a hunk without changes are never emitted: they are discarded when the next hunk
+    /// starts.
+    fn hold_back_hunk_line(&mut self) -> std::io::Result<bool> {
+        if let State::HunkHeader(line, raw_line) = &self.state {
+            self.held_back_hunk_header = Some((line.clone(), raw_line.clone()));
+            self.held_back_hunk_lines.clear();
+            self.held_back_hunk_has_changes = false;
+        } else if self.held_back_hunk_has_changes {
+            return Ok(false);
+        }
+        match self.line.chars().next() {
+            Some('-') | Some('+') => {
+                self.held_back_hunk_has_changes = true;
+                self.emit_held_back_hunk_lines()?;
+                Ok(false)
+            }
+            _ => {
+                self.held_back_hunk_lines.push((
+                    self.line.clone(),
+                    self.raw_line.clone(),
+                    self.line_ending,
+                ));
+                self.state = State::HunkZero;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Emit the held back hunk header and lines preceding the first change of the hunk.
+    fn emit_held_back_hunk_lines(&mut self) -> std::io::Result<()> {
+        if let Some((line, raw_line)) = self.held_back_hunk_header.take() {
+            self.emit_hunk_header_line(&line, &raw_line)?;
+        }
+        self.state = State::HunkZero;
+        let current_line = (
+            std::mem::take(&mut self.line),
+            std::mem::take(&mut self.raw_line),
+            self.line_ending,
+        );
+        for (line, raw_line, line_ending) in std::mem::take(&mut self.held_back_hunk_lines) {
+            self.line = line;
+            self.raw_line = raw_line;
+            self.line_ending = line_ending;
+            self.handle_hunk_line()?;
+        }
+        let (line, raw_line, line_ending) = current_line;
+        self.line = line;
+        self.raw_line = raw_line;
+        self.line_ending = line_ending;
+        Ok(())
+    }
+
+    /// If the current line is a minus or plus line matched by --redact-lines, return it with its
+    /// content replaced by --redact-placeholder, together with a raw line, to be emitted
+    /// unchanged, which has the placeholder painted in --redact-style.
+    fn get_redacted_line(&self) -> Option<(String, String)> {
+        if !self.painter.is_redacted(&self.line) {
+            return None;
+        }
+        let marker = &self.line[..1];
+        let placeholder = &self.config.redact_placeholder;
+        let raw_line = format!("{}{}", marker, self.config.redact_style.paint(placeholder));
+        Some((
+            format!("{}{}", marker, placeholder),
+            self.painter.prepare_raw_line(&raw_line),
+        ))
+    }
 }
@@ -20,16 +20,28 @@
 // ```
 
 use std::fmt::Write as FmtWrite;
+use std::path::Path;
 
 use lazy_static::lazy_static;
 use regex::Regex;
+use syntect::parsing::SyntaxReference;
+use unicode_segmentation::UnicodeSegmentation;
 
 use super::draw;
+use crate::ci_annotations::HunkAnnotation;
 use crate::config::Config;
 use crate::delta::{self, State, StateMachine};
 use crate::features;
 use crate::paint::{BgShouldFill, Painter};
-use crate::style::DecorationStyle;
+use crate::style::{DecorationStyle, Style};
+
+/// Where the code fragment is placed in the hunk header, relative to the file path and line
+/// number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HunkHeaderContextPosition {
+    Left,
+    Right,
+}
 
 impl<'a> StateMachine<'a> {
     #[inline]
@@ -47,11 +59,16 @@ impl<'a> StateMachine<'a> {
 
     /// Emit the hunk header, with any requested decoration.
     pub fn emit_hunk_header_line(&mut self, line: &str, raw_line: &str) -> std::io::Result<bool> {
-        self.painter.paint_buffered_minus_and_plus_lines();
+        self.painter.paint_buffered_lines_at_hunk_end();
         self.painter.set_highlighter();
         self.painter.emit()?;
 
         let (code_fragment, line_numbers) = parse_hunk_header(line);
+        if self.config.github_actions_annotations {
+            self.write_hunk_annotation()?;
+            self.hunk_annotation =
+                HunkAnnotation::new(&self.minus_file, &self.plus_file, &line_numbers);
+        }
         if self.config.line_numbers {
             self.painter
                 .line_numbers_data
@@ -60,9 +77,28 @@ impl<'a> StateMachine<'a> {
                 .initialize_hunk(&line_numbers, self.plus_file.to_string());
         }
 
-        if self.config.hunk_header_style.is_raw {
-            write_hunk_header_raw(&mut self.painter, line, raw_line, self.config)?;
-        } else if self.config.hunk_header_style.is_omitted {
+        let file = if self.plus_file == "/dev/null" {
+            &self.minus_file
+        } else {
+            &self.plus_file
+        };
+        let hunk_header_style = *self.config.get_hunk_header_style(file);
+        self.painter.file_line_style = if self.minus_file == "/dev/null" {
+            self.config.file_added_line_style
+        } else if self.plus_file == "/dev/null" {
+            self.config.file_deleted_line_style
+        } else {
+            None
+        };
+        if hunk_header_style.is_raw {
+            write_hunk_header_raw(
+                &mut self.painter,
+                line,
+                raw_line,
+                hunk_header_style,
+                self.config,
+            )?;
+        } else if hunk_header_style.is_omitted {
             writeln!(self.painter.writer)?;
         } else {
             // Add a blank line below the hunk-header-line for readability, unless
@@ -76,11 +112,7 @@ impl<'a> StateMachine<'a> {
                 &line_numbers,
                 &mut self.painter,
                 line,
-                if self.plus_file == "/dev/null" {
-                    &self.minus_file
-                } else {
-                    &self.plus_file
-                },
+                file,
                 self.config,
             )?;
         };
@@ -112,7 +144,7 @@ lazy_static! {
 /// Given input like
 /// "@@ -74,15 +74,14 @@ pub fn delta("
 /// Return " pub fn delta(" and a vector of (line_number, hunk_length) tuples.
-fn parse_hunk_header(line: &str) -> (String, Vec<(usize, usize)>) {
+pub fn parse_hunk_header(line: &str) -> (String, Vec<(usize, usize)>) {
     let caps = HUNK_HEADER_REGEX.captures(line).unwrap();
     let file_coordinates = &caps[1];
     let line_numbers_and_hunk_lengths = HUNK_HEADER_FILE_COORDINATE_REGEX
@@ -137,24 +169,54 @@ fn write_hunk_header_raw(
     painter: &mut Painter,
     line: &str,
     raw_line: &str,
+    hunk_header_style: Style,
     config: &Config,
 ) -> std::io::Result<()> {
     let (mut draw_fn, pad, decoration_ansi_term_style) =
-        draw::get_draw_function(config.hunk_header_style.decoration_style);
-    if config.hunk_header_style.decoration_style != DecorationStyle::NoDecoration {
+        draw::get_draw_function(hunk_header_style.decoration_style);
+    if hunk_header_style.decoration_style != DecorationStyle::NoDecoration {
         writeln!(painter.writer)?;
     }
+    let raw_line = match config.hunk_header_marker_style {
+        Some(marker_style) => paint_raw_hunk_header_parts(line, marker_style, config)
+            .unwrap_or_else(|| raw_line.to_string()),
+        None => raw_line.to_string(),
+    };
     draw_fn(
         painter.writer,
         &format!("{}{}", line, if pad { " " } else { "" }),
         &format!("{}{}", raw_line, if pad { " " } else { "" }),
         &config.decorations_width,
-        config.hunk_header_style,
+        hunk_header_style,
         decoration_ansi_term_style,
     )?;
     Ok(())
 }
 
+/// Repaint a raw hunk header in three parts: the @@ markers in hunk-header-marker-style, the line
+/// ranges in hunk-header-line-number-style, and the code fragment in hunk-header-context-style.
+fn paint_raw_hunk_header_parts(line: &str, marker_style: Style, config: &Config) -> Option<String> {
+    let caps = HUNK_HEADER_REGEX.captures(line)?;
+    // Combined diffs use more than two @ characters.
+    let marker = &line[..line.find(|c| c != '@')?];
+    let mut painted = format!(
+        "{} {} {}",
+        marker_style.paint(marker),
+        config
+            .hunk_header_line_number_style
+            .paint(caps[1].trim_end()),
+        marker_style.paint(marker),
+    );
+    let code_fragment = &caps[2];
+    if !code_fragment.is_empty() {
+        let context_style = config
+            .hunk_header_context_style
+            .unwrap_or(config.null_style);
+        painted.push_str(&context_style.paint(code_fragment).to_string());
+    }
+    Some(painted)
+}
+
 fn write_hunk_header(
     code_fragment: &str,
     line_numbers: &[(usize, usize)],
@@ -163,20 +225,36 @@ fn write_hunk_header(
     plus_file: &str,
     config: &Config,
 ) -> std::io::Result<()> {
+    let hunk_header_style = *config.get_hunk_header_style(plus_file);
     let (mut draw_fn, _, decoration_ansi_term_style) =
-        draw::get_draw_function(config.hunk_header_style.decoration_style);
+        draw::get_draw_function(hunk_header_style.decoration_style);
     let line = if config.color_only {
         format!(" {}", &line)
     } else if !code_fragment.is_empty() {
-        format!("{} ", code_fragment)
+        format!(
+            "{} ",
+            truncate_code_fragment(code_fragment, config.hunk_header_context_max_length)
+        )
     } else {
         "".to_string()
     };
 
-    let file_with_line_number = get_painted_file_with_line_number(line_numbers, plus_file, config);
+    let scope = if config.hunk_header_file_path_format.contains("{scope}") {
+        get_scope_name(painter.syntax, plus_file)
+    } else {
+        "".to_string()
+    };
+    let file_with_line_number =
+        get_painted_file_with_line_number(line_numbers, plus_file, &scope, config);
 
     if !line.is_empty() || !file_with_line_number.is_empty() {
-        write_to_output_buffer(&file_with_line_number, line, painter, config);
+        write_to_output_buffer(
+            &file_with_line_number,
+            line,
+            painter,
+            hunk_header_style,
+            config,
+        );
         draw_fn(
             painter.writer,
             &painter.output_buffer,
@@ -194,15 +272,20 @@ fn write_hunk_header(
 fn get_painted_file_with_line_number(
     line_numbers: &[(usize, usize)],
     plus_file: &str,
+    scope: &str,
     config: &Config,
 ) -> String {
     let mut file_with_line_number = Vec::new();
     let plus_line_number = line_numbers[line_numbers.len() - 1].0;
     if config.hunk_header_style_include_file_path {
-        file_with_line_number.push(config.hunk_header_file_style.paint(plus_file))
+        file_with_line_number.push(
+            config
+                .hunk_header_file_style
+                .paint(format_file_path(plus_file, scope, config)),
+        )
     };
     if config.hunk_header_style_include_line_number
-        && !config.hunk_header_style.is_raw
+        && !config.get_hunk_header_style(plus_file).is_raw
         && !config.color_only
     {
         if !file_with_line_number.is_empty() {
@@ -228,10 +311,59 @@ fn get_painted_file_with_line_number(
     }
 }
 
+/// The name of the syntect scope of `syntax` (e.g. "source.rust"), for the {scope} placeholder of
+/// hunk-header-file-path-format. Falls back to the extension of `file` if no syntax was found
+/// for it.
+fn get_scope_name(syntax: &SyntaxReference, file: &str) -> String {
+    let scope = syntax.scope.build_string();
+    if scope != "text.plain" {
+        return scope;
+    }
+    Path::new(file)
+        .extension()
+        .map(|extension| extension.to_string_lossy().into_owned())
+        .unwrap_or(scope)
+}
+
+/// Substitute the {file}, {file_name} and {scope} placeholders of hunk-header-file-path-format,
+/// using the displayed form of `file`.
+fn format_file_path(file: &str, scope: &str, config: &Config) -> String {
+    let file = config.display_file_path(file);
+    let file = file.as_ref();
+    let file_name = Path::new(file)
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_else(|| file.into());
+    config
+        .hunk_header_file_path_format
+        .replace("{file_name}", &file_name)
+        .replace("{file}", file)
+        .replace("{scope}", scope)
+}
+
+/// Truncate the code fragment to at most `max_length` characters, not counting the surrounding
+/// whitespace. A `max_length` of zero means no limit.
+fn truncate_code_fragment(code_fragment: &str, max_length: usize) -> String {
+    let text = code_fragment.trim();
+    if max_length == 0 || text.graphemes(true).count() <= max_length {
+        return code_fragment.to_string();
+    }
+    // unwrap: text is a substring of code_fragment, and has more than max_length graphemes.
+    let start = code_fragment.find(text).unwrap();
+    let end = text.grapheme_indices(true).nth(max_length).unwrap().0;
+    format!(
+        "{}{}{}",
+        &code_fragment[..start],
+        &text[..end],
+        &code_fragment[start + text.len()..]
+    )
+}
+
 fn write_to_output_buffer(
     file_with_line_number: &str,
     line: String,
     painter: &mut Painter,
+    hunk_header_style: Style,
     config: &Config,
 ) {
     if config.navigate {
@@ -241,23 +373,38 @@ fn write_to_output_buffer(
             config.hunk_header_file_style.paint(&config.hunk_label)
         );
     }
-    if !file_with_line_number.is_empty() {
+    let context_on_left = config.hunk_header_context_position == HunkHeaderContextPosition::Left
+        && !config.color_only;
+    if !file_with_line_number.is_empty() && !context_on_left {
         let _ = write!(&mut painter.output_buffer, "{}: ", file_with_line_number);
     }
     if !line.is_empty() {
-        painter.syntax_highlight_and_paint_line(
-            &line,
-            config.hunk_header_style,
-            delta::State::HunkHeader("".to_owned(), "".to_owned()),
-            BgShouldFill::No,
-        );
-        painter.output_buffer.pop(); // trim newline
+        match config.hunk_header_context_style {
+            Some(style) if !config.color_only => {
+                painter
+                    .output_buffer
+                    .push_str(&style.paint(line).to_string());
+            }
+            _ => {
+                painter.syntax_highlight_and_paint_line(
+                    &line,
+                    hunk_header_style,
+                    delta::State::HunkHeader("".to_owned(), "".to_owned()),
+                    BgShouldFill::No,
+                );
+                painter.output_buffer.pop(); // trim newline
+            }
+        }
+    }
+    if !file_with_line_number.is_empty() && context_on_left {
+        let _ = write!(&mut painter.output_buffer, "{} ", file_with_line_number);
     }
 }
 
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::ansi::strip_ansi_codes;
     use crate::tests::integration_test_utils;
 
     #[test]
@@ -310,11 +457,137 @@ pub mod tests {
         assert_eq!(line_numbers_and_hunk_lengths[1], (358, 15),);
         assert_eq!(line_numbers_and_hunk_lengths[2], (358, 16),);
     }
+
+    #[test]
+    fn test_truncate_code_fragment() {
+        assert_eq!(truncate_code_fragment(" fn f() {", 40), " fn f() {");
+        assert_eq!(truncate_code_fragment(" fn f() {", 0), " fn f() {");
+        assert_eq!(truncate_code_fragment(" fn f() {\n", 4), " fn f\n");
+        assert_eq!(truncate_code_fragment(" fn f() {", 5), " fn f");
+    }
+
+    #[test]
+    fn test_hunk_header_context_position_and_style() {
+        const DIFF: &str = "\
+diff --git a/a.rs b/a.rs
+index 7898192..6178079 100644
+--- a/a.rs
++++ b/a.rs
+@@ -10,1 +10,1 @@ fn some_function_with_a_long_name() {
+-a
++b
+";
+        let config = integration_test_utils::make_config_from_args(&[
+            "--hunk-header-decoration-style",
+            "none",
+            "--hunk-header-context-max-length",
+            "16",
+        ]);
+        let output = strip_ansi_codes(&integration_test_utils::run_delta(DIFF, &config));
+        assert!(output.contains("\n10:  fn some_function \n"));
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--hunk-header-decoration-style",
+            "none",
+            "--hunk-header-context-position",
+            "left",
+            "--hunk-header-context-style",
+            "red",
+        ]);
+        let output = integration_test_utils::run_delta(DIFF, &config);
+        assert!(
+            strip_ansi_codes(&output).contains("\n fn some_function_with_a_long_name() { 10 \n")
+        );
+        let painted_context =
+            ansi_term::Color::Red.paint(" fn some_function_with_a_long_name() { ");
+        assert!(output.contains(&painted_context.to_string()));
+    }
+
+    #[test]
+    fn test_hunk_header_marker_style() {
+        const DIFF: &str = "\
+diff --git a/a.rs b/a.rs
+index 7898192..6178079 100644
+--- a/a.rs
++++ b/a.rs
+@@ -10,1 +10,1 @@ fn f() {
+-a
++b
+";
+        let config = integration_test_utils::make_config_from_args(&[
+            "--hunk-header-style",
+            "raw",
+            "--hunk-header-decoration-style",
+            "none",
+            "--hunk-header-marker-style",
+            "red",
+            "--hunk-header-line-number-style",
+            "green",
+            "--hunk-header-context-style",
+            "blue",
+        ]);
+        let output = integration_test_utils::run_delta(DIFF, &config);
+        assert!(strip_ansi_codes(&output).contains("@@ -10,1 +10,1 @@ fn f() {"));
+        let painted_header = format!(
+            "{} {} {}{}",
+            ansi_term::Color::Red.paint("@@"),
+            ansi_term::Color::Green.paint("-10,1 +10,1"),
+            ansi_term::Color::Red.paint("@@"),
+            ansi_term::Color::Blue.paint(" fn f() {"),
+        );
+        assert!(output.contains(&painted_header));
+    }
+
+    #[test]
+    fn test_hunk_header_style_by_language() {
+        const DIFF: &str = "\
+diff --git a/a.rs b/a.rs
+index 7898192..6178079 100644
+--- a/a.rs
++++ b/a.rs
+@@ -10,1 +10,1 @@ fn f() {
+-a
++b
+diff --git a/b.py b/b.py
+index 7898192..6178079 100644
+--- a/b.py
++++ b/b.py
+@@ -20,1 +20,1 @@ def g():
+-a
++b
+";
+        let config = integration_test_utils::make_config_from_args(&[
+            "--hunk-header-decoration-style",
+            "none",
+            "--hunk-header-style-by-language",
+            ".RS=omit, *=raw",
+        ]);
+        let output = strip_ansi_codes(&integration_test_utils::run_delta(DIFF, &config));
+        assert!(!output.contains("fn f() {"));
+        assert!(output.contains("@@ -20,1 +20,1 @@ def g():"));
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--hunk-header-style-by-language",
+            "rs=red",
+        ]);
+        assert_eq!(
+            config
+                .get_hunk_header_style("src/a.rs")
+                .ansi_term_style
+                .foreground,
+            Some(ansi_term::Color::Red)
+        );
+        assert_eq!(
+            config.get_hunk_header_style("b.py"),
+            &config.hunk_header_style
+        );
+    }
+
     #[test]
     fn test_get_painted_file_with_line_number_default() {
         let cfg = integration_test_utils::make_config_from_args(&[]);
 
-        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", &cfg);
+        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", "", &cfg);
 
         assert_eq!(result, "\u{1b}[34m3\u{1b}[0m");
     }
@@ -323,7 +596,7 @@ pub mod tests {
     fn test_get_painted_file_with_line_number_hyperlinks() {
         let cfg = integration_test_utils::make_config_from_args(&["--features", "hyperlinks"]);
 
-        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", &cfg);
+        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", "", &cfg);
 
         assert_eq!(result, "some-file");
     }
@@ -337,7 +610,7 @@ pub mod tests {
             "omit",
         ]);
 
-        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", &cfg);
+        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", "", &cfg);
 
         assert_eq!(result, "");
     }
@@ -353,7 +626,7 @@ pub mod tests {
             "hyperlinks",
         ]);
 
-        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", &cfg);
+        let result = get_painted_file_with_line_number(&vec![(3, 4)], "some-file", "", &cfg);
 
         assert_eq!(result, "");
     }
@@ -368,8 +641,62 @@ pub mod tests {
             "--navigate",
         ]);
 
-        let result = get_painted_file_with_line_number(&vec![(3, 4)], "δ some-file", &cfg);
+        let result = get_painted_file_with_line_number(&vec![(3, 4)], "δ some-file", "", &cfg);
 
         assert_eq!(result, "");
     }
+
+    #[test]
+    fn test_get_painted_file_with_line_number_file_path_format() {
+        let cfg = integration_test_utils::make_config_from_args(&["--hunk-header-file-path"]);
+        let result = get_painted_file_with_line_number(
+            &vec![(3, 4)],
+            "src/some-file.rs",
+            "source.rust",
+            &cfg,
+        );
+        assert_eq!(strip_ansi_codes(&result), "src/some-file.rs:3");
+
+        let cfg = integration_test_utils::make_config_from_args(&[
+            "--hunk-header-file-path",
+            "--hunk-header-file-path-format",
+            "{file_name} ({file})",
+        ]);
+        let result = get_painted_file_with_line_number(
+            &vec![(3, 4)],
+            "src/some-file.rs",
+            "source.rust",
+            &cfg,
+        );
+        assert_eq!(
+            strip_ansi_codes(&result),
+            "some-file.rs (src/some-file.rs):3"
+        );
+
+        let cfg = integration_test_utils::make_config_from_args(&[
+            "--hunk-header-file-path",
+            "--hunk-header-file-path-format",
+            "{file} [{scope}]",
+        ]);
+        let result = get_painted_file_with_line_number(
+            &vec![(3, 4)],
+            "src/some-file.rs",
+            "source.rust",
+            &cfg,
+        );
+        assert_eq!(
+            strip_ansi_codes(&result),
+            "src/some-file.rs [source.rust]:3"
+        );
+    }
+
+    #[test]
+    fn test_get_scope_name() {
+        let cfg = integration_test_utils::make_config_from_args(&[]);
+        let rust = cfg.syntax_set.find_syntax_by_extension("rs").unwrap();
+        assert_eq!(get_scope_name(rust, "src/some-file.rs"), "source.rust");
+        let plain_text = cfg.syntax_set.find_syntax_plain_text();
+        assert_eq!(get_scope_name(plain_text, "src/some-file.xyz"), "xyz");
+        assert_eq!(get_scope_name(plain_text, "Dockerfile2"), "text.plain");
+    }
 }
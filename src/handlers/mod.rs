@@ -29,14 +29,15 @@ impl<'a> StateMachine<'a> {
         // See https://github.com/dandavison/delta/issues/60#issuecomment-557485242 for a
         // proposal for more robust parsing logic.
 
-        self.painter.paint_buffered_minus_and_plus_lines();
+        self.painter.paint_buffered_lines_at_hunk_end();
         self.state = to_state;
         if self.should_handle() {
             self.painter.emit()?;
-            file_meta::write_generic_file_meta_header_line(
+            file_meta::write_file_meta_header_line(
                 &self.line,
                 &self.raw_line,
                 &mut self.painter,
+                *self.config.get_style(&self.state),
                 self.config,
             )?;
             handled_line = true;
@@ -3,13 +3,16 @@ extern crate bitflags;
 #[macro_use]
 extern crate error_chain;
 
+mod accessibility;
 mod align;
 mod ansi;
 #[cfg(not(tarpaulin_include))]
 mod bat_utils;
+mod ci_annotations;
 mod cli;
 mod color;
 mod config;
+mod debug_output;
 mod delta;
 mod edits;
 mod env;
@@ -19,6 +22,7 @@ mod git_config;
 mod handlers;
 mod minusplus;
 mod options;
+mod output;
 mod paint;
 mod parse_style;
 mod style;
@@ -29,11 +33,9 @@ mod syntect_utils;
 
 mod tests;
 
-use std::io::{self, ErrorKind};
+use std::io::{self, ErrorKind, Write};
 use std::process;
 
-use bytelines::ByteLinesReader;
-
 use crate::bat_utils::assets::{list_languages, HighlightingAssets};
 use crate::bat_utils::output::OutputType;
 use crate::delta::delta;
@@ -85,8 +87,11 @@ fn run_app() -> std::io::Result<i32> {
     if opt.list_languages {
         list_languages()?;
         return Ok(0);
+    } else if opt.list_features {
+        subcommands::list_features::list_features(&opt)?;
+        return Ok(0);
     } else if opt.list_syntax_themes {
-        subcommands::list_syntax_themes::list_syntax_themes()?;
+        subcommands::list_syntax_themes::list_syntax_themes(&opt)?;
         return Ok(0);
     } else if opt.show_syntax_themes {
         subcommands::show_syntax_themes::show_syntax_themes()?;
@@ -99,6 +104,10 @@ fn run_app() -> std::io::Result<i32> {
     let _show_config = opt.show_config;
     let config = config::Config::from(opt);
 
+    if config.check_contrast {
+        accessibility::warn_about_low_contrast_styles(&config);
+    }
+
     if _show_config {
         let stdout = io::stdout();
         let mut stdout = stdout.lock();
@@ -120,7 +129,17 @@ fn run_app() -> std::io::Result<i32> {
         return Ok(exit_code);
     }
 
-    if let Err(error) = delta(io::stdin().lock().byte_lines(), &mut writer, &config) {
+    if let Some(exit_code) = subcommands::diff::rerun_calling_git_command(&config, &mut writer) {
+        return Ok(exit_code);
+    }
+
+    let result = if config.output_buffer_size > 1 {
+        let mut writer = io::BufWriter::with_capacity(config.output_buffer_size * 1024, writer);
+        delta(io::stdin().lock(), &mut writer, &config).and_then(|_| writer.flush())
+    } else {
+        delta(io::stdin().lock(), &mut writer, &config)
+    };
+    if let Err(error) = result {
         match error.kind() {
             ErrorKind::BrokenPipe => return Ok(0),
             _ => eprintln!("{}", error),
@@ -42,10 +42,7 @@ fn rewrite_style_strings_to_honor_deprecated_minus_plus_options(opt: &mut cli::O
     if let Some(rewritten) = _get_rewritten_minus_plus_style_string(
         &opt.minus_style,
         ("normal", "auto"),
-        (
-            deprecated_minus_foreground_arg,
-            opt.deprecated_minus_background_color.as_deref(),
-        ),
+        (deprecated_minus_foreground_arg, None),
         "minus",
     ) {
         opt.minus_style = rewritten;
@@ -61,14 +58,6 @@ fn rewrite_style_strings_to_honor_deprecated_minus_plus_options(opt: &mut cli::O
     ) {
         opt.minus_emph_style = rewritten;
     }
-    if let Some(rewritten) = _get_rewritten_minus_plus_style_string(
-        &opt.plus_style,
-        ("syntax", "auto"),
-        (None, opt.deprecated_plus_background_color.as_deref()),
-        "plus",
-    ) {
-        opt.plus_style = rewritten;
-    }
     if let Some(rewritten) = _get_rewritten_minus_plus_style_string(
         &opt.plus_emph_style,
         ("syntax", "auto"),
@@ -44,7 +44,12 @@ macro_rules! set_options {
                 "24-bit-color",
                 "diff-highlight", // Does not exist as a flag on config
                 "diff-so-fancy", // Does not exist as a flag on config
+                "high-contrast", // Does not exist as a flag on config
                 "features",  // Processed differently
+                "feature-priority", // Processed with features
+                "no-feature", // Processed with features
+                "file-path-regex-transform", // May be given multiple times
+                "redact-lines", // May be given multiple times
                 // Set prior to the rest
                 "no-gitconfig",
                 "dark",
@@ -89,6 +94,7 @@ pub fn set_options(
     }
 
     let features = gather_features(opt, &builtin_features, git_config);
+    let features = apply_feature_overrides(features, opt, git_config);
     opt.features = features.join(" ");
 
     // Set light, dark, and syntax-theme.
@@ -122,68 +128,130 @@ pub fn set_options(
 
     set_options!(
         [
+            binary_diff_style,
             color_only,
+            column_shade_even,
+            column_shade_odd,
             commit_decoration_style,
             commit_regex,
             commit_style,
+            commit_timestamp_format,
+            commit_timestamp_timezone,
+            copy_mode,
+            copy_mode_format,
+            context_after,
+            context_before,
             default_language,
+            diff_algorithm,
             diff_stat_align_width,
             file_added_label,
+            file_added_line_style,
             file_copied_label,
             file_decoration_style,
+            file_deleted_line_style,
             file_modified_label,
             file_removed_label,
             file_renamed_label,
             hunk_label,
+            file_stat_bar_style,
+            file_stat_bar_width,
+            file_stat_width,
             file_style,
+            file_style_map,
+            git_config_parameters,
+            github_actions_annotations,
+            hunk_header_context_max_length,
+            hunk_header_context_position,
+            hunk_header_context_style,
             hunk_header_decoration_style,
+            hunk_header_file_path,
+            hunk_header_file_path_format,
             hunk_header_file_style,
             hunk_header_line_number_style,
+            hunk_header_marker_style,
             hunk_header_style,
+            hunk_header_style_by_language,
             hyperlinks,
             hyperlinks_commit_link_format,
             hyperlinks_file_link_format,
+            inline_hint_color,
             inline_hint_style,
             inspect_raw_lines,
+            invert_diff,
             keep_plus_minus_markers,
             line_buffer_size,
+            line_ending_style,
             max_line_distance,
             max_line_length,
+            min_panel_width,
+            minus_color,
             // Hack: minus-style must come before minus-*emph-style because the latter default
             // dynamically to the value of the former.
             minus_style,
             minus_emph_style,
             minus_empty_line_marker_style,
+            minus_empty_line_style,
             minus_non_emph_style,
             minus_non_emph_style,
             navigate,
+            no_pager,
+            output_buffer_size,
             line_fill_method,
             line_numbers,
             line_numbers_left_format,
             line_numbers_left_style,
+            line_numbers_minus_format,
             line_numbers_minus_style,
+            line_numbers_plus_format,
             line_numbers_plus_style,
+            line_numbers_relative_to_hunk,
             line_numbers_right_format,
             line_numbers_right_style,
+            line_numbers_separator_format,
+            line_numbers_separator_style,
+            separator_before_left_panel,
+            separator_between_panels,
             line_numbers_zero_style,
+            line_range_style,
+            output_format,
             pager,
             paging_mode,
+            plus_color,
             // Hack: plus-style must come before plus-*emph-style because the latter default
             // dynamically to the value of the former.
             plus_style,
             plus_emph_style,
             plus_empty_line_marker_style,
+            plus_empty_line_style,
             plus_non_emph_style,
             raw,
+            redact_placeholder,
+            redact_style,
             relative_paths,
+            show_line_endings,
+            show_line_endings_on,
             show_themes,
             side_by_side,
+            side_by_side_column_separator_style,
+            side_by_side_direction,
+            syntax_disabled_for,
+            syntax_disabled_for_default,
+            syntax_ignore_case,
+            syntax_line_range,
+            syntax_padding,
+            syntax_wrap_color_columns,
+            submodule_style,
+            suppress_diff_binary_notice,
+            suppress_empty_hunk_context,
+            wrap_indicator_newline_behavior,
             wrap_max_lines,
+            wrap_overflow_symbol,
             wrap_right_prefix_symbol,
             wrap_right_percent,
             wrap_right_symbol,
             wrap_left_symbol,
             tab_width,
+            themes_dir,
             tokenization_regex,
             true_color,
             whitespace_error_style,
@@ -198,13 +266,28 @@ pub fn set_options(
         true
     );
 
+    // --redact-lines and --file-path-regex-transform can be given multiple times on the command
+    // line, and their keys can be set multiple times in git config.
+    if let Some(git_config) = git_config.as_ref() {
+        if opt.redact_lines.is_empty() {
+            opt.redact_lines = git_config.get_all("delta.redact-lines");
+        }
+        if opt.file_path_regex_transform.is_empty() {
+            opt.file_path_regex_transform = git_config.get_all("delta.file-path-regex-transform");
+        }
+    }
+
     // Setting ComputedValues
     set_widths_and_isatty(opt);
     set_true_color(opt);
     theme::set__is_light_mode__syntax_theme__syntax_set(opt, assets);
     opt.computed.inspect_raw_lines =
         cli::InspectRawLines::from_str(&opt.inspect_raw_lines).unwrap();
-    opt.computed.paging_mode = parse_paging_mode(&opt.paging_mode);
+    opt.computed.paging_mode = if opt.no_pager {
+        PagingMode::Never
+    } else {
+        parse_paging_mode(&opt.paging_mode)
+    };
 
     // --color-only is used for interactive.diffFilter (git add -p). side-by-side, and
     // **-decoration-style cannot be used there (does not emit lines in 1-1 correspondence with raw git output).
@@ -335,6 +418,9 @@ fn gather_features(
     if opt.diff_so_fancy {
         gather_builtin_features_recursively("diff-so-fancy", &mut features, builtin_features, opt);
     }
+    if opt.high_contrast {
+        gather_builtin_features_recursively("high-contrast", &mut features, builtin_features, opt);
+    }
     if opt.hyperlinks {
         gather_builtin_features_recursively("hyperlinks", &mut features, builtin_features, opt);
     }
@@ -376,6 +462,39 @@ fn gather_features(
     Vec::<String>::from(features)
 }
 
+/// Remove the features named by --no-feature from the gathered feature list `features`, and then
+/// move the features named by --feature-priority to its high-priority (right-hand) end, so that
+/// the first of them is consulted first. Both options may also be set in the [delta] section of
+/// git config; a value given on the command line replaces the git config value.
+fn apply_feature_overrides(
+    mut features: Vec<String>,
+    opt: &cli::Opt,
+    git_config: &Option<GitConfig>,
+) -> Vec<String> {
+    let get_list = |value: &str, name: &str| -> String {
+        match git_config {
+            Some(git_config) if value.is_empty() => git_config
+                .get::<String>(&format!("delta.{}", name))
+                .unwrap_or_default(),
+            _ => value.to_string(),
+        }
+    };
+    let no_feature = get_list(&opt.no_feature, "no-feature");
+    let feature_priority = get_list(&opt.feature_priority, "feature-priority");
+
+    let disabled: HashSet<&str> = no_feature.split_whitespace().collect();
+    features.retain(|feature| !disabled.contains(feature.as_str()));
+
+    // `split_feature_string` yields the lowest priority feature first.
+    for feature in split_feature_string(&feature_priority) {
+        if let Some(i) = features.iter().position(|f| f == feature) {
+            let feature = features.remove(i);
+            features.push(feature);
+        }
+    }
+    features
+}
+
 /// Add to feature list `features` all features in the tree rooted at `feature`.
 fn gather_features_recursively(
     feature: &str,
@@ -763,6 +882,19 @@ pub mod tests {
         remove_file(git_config_path).unwrap();
     }
 
+    #[test]
+    fn test_no_pager() {
+        let opt = integration_test_utils::make_options_from_args(&[]);
+        assert_eq!(opt.computed.paging_mode, PagingMode::QuitIfOneScreen);
+
+        let opt = integration_test_utils::make_options_from_args(&["--no-pager"]);
+        assert_eq!(opt.computed.paging_mode, PagingMode::Never);
+
+        let opt =
+            integration_test_utils::make_options_from_args(&["--paging", "always", "--no-pager"]);
+        assert_eq!(opt.computed.paging_mode, PagingMode::Never);
+    }
+
     #[test]
     fn test_width_in_git_config_is_honored() {
         let git_config_contents = b"
@@ -785,6 +917,75 @@ pub mod tests {
         remove_file(git_config_path).unwrap();
     }
 
+    #[test]
+    fn test_multi_valued_options_in_git_config() {
+        let git_config_contents = b"
+[delta]
+    redact-lines = password
+    redact-lines = token
+    file-path-regex-transform = ^src/=
+    file-path-regex-transform = [.]rs$=.RS
+";
+        let git_config_path = "delta__test_multi_valued_options_in_git_config.gitconfig";
+
+        let opt = integration_test_utils::make_options_from_args_and_git_config(
+            &[],
+            Some(git_config_contents),
+            Some(git_config_path),
+        );
+        assert_eq!(opt.redact_lines, vec!["password", "token"]);
+        assert_eq!(opt.file_path_regex_transform, vec!["^src/=", "[.]rs$=.RS"]);
+
+        // Values given on the command line replace those in git config.
+        let opt = integration_test_utils::make_options_from_args_and_git_config(
+            &["--redact-lines", "secret"],
+            Some(git_config_contents),
+            Some(git_config_path),
+        );
+        assert_eq!(opt.redact_lines, vec!["secret"]);
+
+        remove_file(git_config_path).unwrap();
+    }
+
+    #[test]
+    fn test_config_option_replaces_git_config() {
+        let git_config_contents = b"
+[delta]
+    minus-style = red
+    zero-style = green
+";
+        let git_config_path = "delta__test_config_option_replaces_git_config.gitconfig";
+        let config_file = std::env::temp_dir().join(format!(
+            "delta__test_config_option_{}.gitconfig",
+            std::process::id()
+        ));
+        std::fs::write(&config_file, b"[delta]\n    minus-style = blue\n").unwrap();
+        let config_file = config_file.to_str().unwrap();
+
+        let opt = integration_test_utils::make_options_from_args_and_git_config(
+            &["--config", config_file],
+            Some(git_config_contents),
+            Some(git_config_path),
+        );
+        assert_eq!(opt.minus_style, "blue");
+        // Settings in the automatically discovered git config are not used.
+        assert_eq!(
+            opt.zero_style,
+            integration_test_utils::make_options_from_args(&[]).zero_style
+        );
+
+        // The command line takes precedence over the --config file.
+        let opt = integration_test_utils::make_options_from_args_and_git_config(
+            &["--config", config_file, "--minus-style", "yellow"],
+            Some(git_config_contents),
+            Some(git_config_path),
+        );
+        assert_eq!(opt.minus_style, "yellow");
+
+        remove_file(config_file).unwrap();
+        remove_file(git_config_path).unwrap();
+    }
+
     #[test]
     fn test_parse_width_specifier() {
         use super::parse_width_specifier;
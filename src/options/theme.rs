@@ -11,12 +11,16 @@ use syntect::highlighting::ThemeSet;
 use crate::bat_utils::assets::HighlightingAssets;
 use crate::cli;
 use crate::env;
+use crate::fatal;
 
 #[allow(non_snake_case)]
 pub fn set__is_light_mode__syntax_theme__syntax_set(
     opt: &mut cli::Opt,
-    assets: HighlightingAssets,
+    mut assets: HighlightingAssets,
 ) {
+    if let Some(themes_dir) = &opt.themes_dir {
+        add_themes_from_dir(&mut assets.theme_set, themes_dir);
+    }
     let syntax_theme_name_from_bat_theme = env::get_env_var("BAT_THEME");
     let (is_light_mode, syntax_theme_name) = get_is_light_mode_and_syntax_theme_name(
         opt.syntax_theme.as_ref(),
@@ -34,6 +38,26 @@ pub fn set__is_light_mode__syntax_theme__syntax_set(
     opt.computed.syntax_set = assets.syntax_set;
 }
 
+/// Add the themes in `themes_dir` to the built-in themes. A theme with the same name as a
+/// built-in theme replaces it.
+pub fn add_themes_from_dir(theme_set: &mut ThemeSet, themes_dir: &str) {
+    let custom_theme_set = ThemeSet::load_from_folder(themes_dir).unwrap_or_else(|err| {
+        fatal(format!(
+            "Failed to load syntax themes from {}: {}",
+            themes_dir, err
+        ))
+    });
+    for (name, theme) in custom_theme_set.themes {
+        if theme_set.themes.contains_key(&name) {
+            eprintln!(
+                "delta: syntax theme \"{}\" in {} replaces the built-in theme of the same name.",
+                name, themes_dir
+            );
+        }
+        theme_set.themes.insert(name, theme);
+    }
+}
+
 pub fn is_light_syntax_theme(theme: &str) -> bool {
     LIGHT_SYNTAX_THEMES.contains(&theme) || theme.to_lowercase().contains("light")
 }
@@ -123,6 +147,7 @@ mod tests {
 
     use super::*;
     use crate::color;
+    use crate::subcommands::list_syntax_themes::_list_syntax_themes_for_machines;
     use crate::tests::integration_test_utils;
 
     #[test]
@@ -249,4 +274,71 @@ mod tests {
             );
         }
     }
+
+    /// A directory which is removed when it goes out of scope, also if the test fails.
+    struct TempDir(std::path::PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_themes_dir() {
+        let temp_dir =
+            TempDir(env::temp_dir().join(format!("delta__test_themes_dir_{}", std::process::id())));
+        std::fs::create_dir_all(&temp_dir.0).unwrap();
+        for name in &["DeltaTestTheme", "GitHub"] {
+            std::fs::write(
+                temp_dir.0.join(format!("{}.tmTheme", name)),
+                TM_THEME.replace("THEME_NAME", &format!("Custom {}", name)),
+            )
+            .unwrap();
+        }
+        let themes_dir = temp_dir.0.to_str().unwrap();
+
+        for (syntax_theme, expected_name) in &[
+            ("DeltaTestTheme", "Custom DeltaTestTheme"),
+            // A custom theme replaces the built-in theme of the same name.
+            ("GitHub", "Custom GitHub"),
+        ] {
+            let opt = integration_test_utils::make_options_from_args(&[
+                "--themes-dir",
+                themes_dir,
+                "--syntax-theme",
+                syntax_theme,
+            ]);
+            assert_eq!(
+                opt.computed.syntax_theme.unwrap().name.as_deref(),
+                Some(*expected_name)
+            );
+        }
+
+        let mut output = Vec::new();
+        _list_syntax_themes_for_machines(&mut output, Some(themes_dir)).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("dark\tDeltaTestTheme\n"));
+        assert_eq!(output.matches("\tGitHub\n").count(), 1);
+    }
+
+    const TM_THEME: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>THEME_NAME</string>
+    <key>settings</key>
+    <array>
+        <dict>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#FF0000</string>
+            </dict>
+        </dict>
+    </array>
+</dict>
+</plist>
+"#;
 }
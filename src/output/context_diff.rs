@@ -0,0 +1,284 @@
+use std::io::BufRead;
+use std::io::Write;
+
+use bytelines::ByteLines;
+
+use crate::ansi;
+use crate::config::Config;
+use crate::delta::State;
+use crate::handlers::file_meta::get_file_extension_from_marker_line;
+use crate::handlers::hunk_header::parse_hunk_header;
+use crate::minusplus::*;
+use crate::paint::{BgShouldFill, Painter};
+use crate::style::Style;
+
+/// A hunk of the unified diff input. Its lines are buffered until the hunk is complete, since
+/// the context diff format displays the minus and the plus lines of a hunk in separate sections.
+struct Hunk {
+    // (start, length) of the hunk in the minus and plus file
+    coordinates: MinusPlus<(usize, usize)>,
+    // The number of minus and plus lines of the hunk that have not been read yet
+    remaining: MinusPlus<usize>,
+    lines: Vec<String>,
+}
+
+/// Convert the unified diff input to the context diff format (as produced by `diff -c`). Lines
+/// which are not part of a file header or a hunk are emitted unchanged; the lines marked with '!',
+/// i.e. those of a change which both removes and adds lines, are syntax-highlighted.
+pub fn write_context_diff<I>(
+    mut lines: ByteLines<I>,
+    writer: &mut dyn Write,
+    config: &Config,
+) -> std::io::Result<()>
+where
+    I: BufRead,
+{
+    let mut painter = Painter::new(writer, config);
+    let mut minus_file_line: Option<String> = None;
+    let mut hunk: Option<Hunk> = None;
+    while let Some(Ok(raw_line_bytes)) = lines.next() {
+        let line = ansi::strip_ansi_codes(&String::from_utf8_lossy(raw_line_bytes));
+        if let Some(current_hunk) = hunk.as_mut() {
+            if current_hunk.push(&line) {
+                continue;
+            }
+        }
+        if let Some(finished_hunk) = hunk.take() {
+            write_hunk(&finished_hunk, &mut painter)?;
+        }
+
+        if line.starts_with("@@ ") {
+            // Merge commits have more than two file coordinates, they are emitted unchanged.
+            let (_, coordinates) = parse_hunk_header(&line);
+            if coordinates.len() == 2 {
+                hunk = Some(Hunk::new(coordinates[0], coordinates[1]));
+                continue;
+            }
+        } else if line.starts_with("--- ") && minus_file_line.is_none() {
+            minus_file_line = Some(line);
+            continue;
+        } else if line.starts_with("+++ ") {
+            if let Some(minus_file_line) = minus_file_line.take() {
+                let marker_line = if line.starts_with("+++ /dev/null") {
+                    &minus_file_line
+                } else {
+                    &line
+                };
+                painter.set_syntax(get_file_extension_from_marker_line(marker_line));
+                writeln!(painter.writer, "*** {}", &minus_file_line[4..])?;
+                writeln!(painter.writer, "--- {}", &line[4..])?;
+                continue;
+            }
+        }
+        if let Some(minus_file_line) = minus_file_line.take() {
+            writeln!(painter.writer, "{}", minus_file_line)?;
+        }
+        writeln!(painter.writer, "{}", line)?;
+    }
+
+    if let Some(finished_hunk) = hunk {
+        write_hunk(&finished_hunk, &mut painter)?;
+    }
+    if let Some(minus_file_line) = minus_file_line {
+        writeln!(painter.writer, "{}", minus_file_line)?;
+    }
+    Ok(())
+}
+
+impl Hunk {
+    fn new(minus_coordinates: (usize, usize), plus_coordinates: (usize, usize)) -> Self {
+        Self {
+            coordinates: MinusPlus::new(minus_coordinates, plus_coordinates),
+            remaining: MinusPlus::new(minus_coordinates.1, plus_coordinates.1),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Add `line` to the hunk if it belongs to it, and return whether it does.
+    fn push(&mut self, line: &str) -> bool {
+        match line.chars().next() {
+            // "\ No newline at end of file" belongs to the preceding line.
+            Some('\\') => {}
+            _ if self.remaining[Minus] == 0 && self.remaining[Plus] == 0 => return false,
+            Some('-') => self.remaining[Minus] = self.remaining[Minus].saturating_sub(1),
+            Some('+') => self.remaining[Plus] = self.remaining[Plus].saturating_sub(1),
+            Some(' ') | None => {
+                self.remaining[Minus] = self.remaining[Minus].saturating_sub(1);
+                self.remaining[Plus] = self.remaining[Plus].saturating_sub(1);
+            }
+            _ => return false,
+        }
+        self.lines.push(line.to_string());
+        true
+    }
+
+    /// For each line, whether it is part of a change which both removes and adds lines. These
+    /// lines are marked with '!' instead of '-' or '+'.
+    fn replaced_lines(&self) -> Vec<bool> {
+        let mut is_replaced = vec![false; self.lines.len()];
+        let mut start = 0;
+        while start < self.lines.len() {
+            let change_len = self.lines[start..]
+                .iter()
+                .take_while(|line| line.starts_with(&['-', '+', '\\'][..]))
+                .count();
+            if change_len == 0 {
+                start += 1;
+                continue;
+            }
+            let change = start..start + change_len;
+            let is_replacement = self.lines[change.clone()]
+                .iter()
+                .any(|line| line.starts_with('-'))
+                && self.lines[change.clone()]
+                    .iter()
+                    .any(|line| line.starts_with('+'));
+            for line_is_replaced in &mut is_replaced[change] {
+                *line_is_replaced = is_replacement;
+            }
+            start += change_len;
+        }
+        is_replaced
+    }
+}
+
+fn write_hunk(hunk: &Hunk, painter: &mut Painter) -> std::io::Result<()> {
+    let is_replaced = hunk.replaced_lines();
+    painter.output_buffer.push_str("***************\n");
+    painter.output_buffer.push_str(&format!(
+        "*** {} ****\n",
+        format_range(hunk.coordinates[Minus])
+    ));
+    write_hunk_section(hunk, Minus, &is_replaced, painter);
+    painter.output_buffer.push_str(&format!(
+        "--- {} ----\n",
+        format_range(hunk.coordinates[Plus])
+    ));
+    write_hunk_section(hunk, Plus, &is_replaced, painter);
+    painter.emit()
+}
+
+/// Write the lines of the minus or the plus file. Like `diff -c`, a section without any removed
+/// or added lines respectively is left empty.
+fn write_hunk_section(
+    hunk: &Hunk,
+    side: MinusPlusIndex,
+    is_replaced: &[bool],
+    painter: &mut Painter,
+) {
+    let marker = if side == Minus { '-' } else { '+' };
+    if !hunk.lines.iter().any(|line| line.starts_with(marker)) {
+        return;
+    }
+    painter.set_highlighter();
+    // Whether the preceding line belongs to this section.
+    let mut is_in_section = false;
+    for (line, &is_replaced) in hunk.lines.iter().zip(is_replaced) {
+        let text = line.get(1..).unwrap_or("");
+        match line.chars().next() {
+            Some('\\') => {
+                if is_in_section {
+                    painter.output_buffer.push_str(line);
+                    painter.output_buffer.push('\n');
+                }
+                continue;
+            }
+            Some(c) if c == marker && is_replaced => {
+                painter.output_buffer.push_str("! ");
+                painter.syntax_highlight_and_paint_line(
+                    &format!(" {}", text),
+                    Style {
+                        is_syntax_highlighted: true,
+                        ..Style::new()
+                    },
+                    State::HunkHeader("".to_owned(), "".to_owned()),
+                    BgShouldFill::No,
+                );
+                is_in_section = true;
+            }
+            Some(c) if c == marker => {
+                painter
+                    .output_buffer
+                    .push_str(&format!("{} {}\n", marker, text));
+                is_in_section = true;
+            }
+            Some('-') | Some('+') => is_in_section = false,
+            _ => {
+                painter.output_buffer.push_str(&format!("  {}\n", text));
+                is_in_section = true;
+            }
+        }
+    }
+}
+
+/// Format the (start, length) coordinates of a hunk as a context diff line range.
+fn format_range((start, length): (usize, usize)) -> String {
+    if length <= 1 {
+        format!("{}", start)
+    } else {
+        format!("{},{}", start, start + length - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ansi::strip_ansi_codes;
+    use crate::tests::integration_test_utils::{make_config_from_args, run_delta};
+
+    #[test]
+    fn test_context_diff() {
+        let config = make_config_from_args(&["--output-format", "context-diff"]);
+        let output = run_delta(DIFF, &config);
+        assert_eq!(strip_ansi_codes(&output), CONTEXT_DIFF);
+
+        let replaced_line = output.lines().nth(7).unwrap();
+        assert!(replaced_line.starts_with("! "));
+        assert_ne!(replaced_line, "! b = 2");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for output-format: unified")]
+    fn test_invalid_output_format() {
+        make_config_from_args(&["--output-format", "unified"]);
+    }
+
+    const DIFF: &str = "\
+diff --git a/a.py b/a.py
+index 223ca50..e1c3f9b 100644
+--- a/a.py
++++ b/a.py
+@@ -1,4 +1,3 @@
+ a = 1
+-b = 2
++bb = 2
+ c = 3
+-d = 4
+@@ -10,2 +9,3 @@
+ x = 1
++y = 2
+ z = 3
+";
+
+    const CONTEXT_DIFF: &str = "\
+diff --git a/a.py b/a.py
+index 223ca50..e1c3f9b 100644
+*** a/a.py
+--- b/a.py
+***************
+*** 1,4 ****
+  a = 1
+! b = 2
+  c = 3
+- d = 4
+--- 1,3 ----
+  a = 1
+! bb = 2
+  c = 3
+***************
+*** 10,11 ****
+--- 9,11 ----
+  x = 1
++ y = 2
+  z = 3
+";
+}
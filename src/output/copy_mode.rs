@@ -0,0 +1,92 @@
+use std::io::BufRead;
+use std::io::Write;
+
+use bytelines::ByteLines;
+
+use crate::ansi;
+use crate::config::Config;
+use crate::delta::StateMachine;
+
+/// The format of the plain text written by --copy-mode.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CopyModeFormat {
+    /// The unified diff input, without ANSI escape sequences.
+    Diff,
+    /// The unified diff input in a Markdown ```diff code block.
+    Markdown,
+    /// Delta's normal output, without ANSI escape sequences and trailing whitespace.
+    Plain,
+}
+
+/// Write the input as plain text suitable for pasting elsewhere, e.g. into a chat message or a
+/// pull request comment. See --copy-mode.
+pub fn write_copy_mode<I>(reader: I, writer: &mut dyn Write, config: &Config) -> std::io::Result<()>
+where
+    I: BufRead,
+{
+    if config.copy_mode_format == CopyModeFormat::Plain {
+        let mut rendered = Vec::new();
+        StateMachine::new(&mut rendered, config).consume(reader, None)?;
+        for line in String::from_utf8_lossy(&rendered).lines() {
+            writeln!(writer, "{}", ansi::strip_ansi_codes(line).trim_end())?;
+        }
+        return Ok(());
+    }
+    if config.copy_mode_format == CopyModeFormat::Markdown {
+        writeln!(writer, "```diff")?;
+    }
+    let mut lines = ByteLines::new(reader);
+    while let Some(Ok(raw_line_bytes)) = lines.next() {
+        let line = ansi::strip_ansi_codes(&String::from_utf8_lossy(raw_line_bytes));
+        writeln!(writer, "{}", line.strip_suffix('\r').unwrap_or(&line))?;
+    }
+    if config.copy_mode_format == CopyModeFormat::Markdown {
+        writeln!(writer, "```")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::integration_test_utils::{make_config_from_args, run_delta};
+
+    #[test]
+    fn test_copy_mode_diff() {
+        let config = make_config_from_args(&["--copy-mode"]);
+        let output = run_delta(&DIFF.replace("-b = 2", "\x1b[31m-b = 2\x1b[0m"), &config);
+        assert_eq!(output, DIFF);
+    }
+
+    #[test]
+    fn test_copy_mode_markdown() {
+        let config = make_config_from_args(&["--copy-mode", "--copy-mode-format", "markdown"]);
+        let output = run_delta(DIFF, &config);
+        assert_eq!(output, format!("```diff\n{}```\n", DIFF));
+    }
+
+    #[test]
+    fn test_copy_mode_plain() {
+        let config = make_config_from_args(&["--copy-mode", "--copy-mode-format", "plain"]);
+        let output = run_delta(DIFF, &config);
+        assert!(!output.contains('\x1b'));
+        assert!(output.contains("\na.py\n"));
+        assert!(output.contains("\nbb = 2\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for copy-mode-format: html")]
+    fn test_invalid_copy_mode_format() {
+        make_config_from_args(&["--copy-mode", "--copy-mode-format", "html"]);
+    }
+
+    const DIFF: &str = "\
+diff --git a/a.py b/a.py
+index 223ca50..e1c3f9b 100644
+--- a/a.py
++++ b/a.py
+@@ -1,2 +1,2 @@
+ a = 1
+-b = 2
++bb = 2
+";
+}
@@ -0,0 +1,11 @@
+/// This module contains the alternatives to delta's own output format, see --output-format and
+/// --copy-mode.
+pub mod context_diff;
+pub mod copy_mode;
+
+/// The format of delta's output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Delta,
+    ContextDiff,
+}
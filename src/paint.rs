@@ -5,10 +5,11 @@ use syntect::easy::HighlightLines;
 use syntect::highlighting::Style as SyntectStyle;
 use syntect::parsing::{SyntaxReference, SyntaxSet};
 use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
 
 use crate::ansi;
 use crate::config::{self, delta_unreachable};
-use crate::delta::State;
+use crate::delta::{self, State};
 use crate::edits;
 use crate::features::line_numbers;
 use crate::features::side_by_side::ansifill;
@@ -18,18 +19,34 @@ use crate::paint::superimpose_style_sections::superimpose_style_sections;
 use crate::style::Style;
 use crate::wrapping::wrap_minusplus_block;
 
+/// Placed in front of the lines of --syntax-line-range.
+const LINE_RANGE_MARKER: &str = "▎";
+
 pub struct Painter<'p> {
     pub minus_lines: Vec<(String, State)>,
     pub plus_lines: Vec<(String, State)>,
     pub writer: &'p mut dyn Write,
     pub syntax: &'p SyntaxReference,
     pub highlighter: Option<HighlightLines<'p>>,
+    /// Whether --syntax-disabled-for disables syntax highlighting for the current file.
+    pub syntax_disabled: bool,
     pub config: &'p config::Config,
     pub output_buffer: String,
     // If config.line_numbers is true, then the following is always Some().
     // In side-by-side mode it is always Some (but possibly an empty one), even
     // if config.line_numbers is false. See `UseFullPanelWidth` as well.
     pub line_numbers_data: Option<line_numbers::LineNumbersData<'p>>,
+    // With --syntax-padding, --context-before or --context-after, whether the current hunk has
+    // changed lines yet. Unchanged lines are held back in `zero_lines` until it is known whether
+    // they precede the first change of the hunk, or follow its last change: these are the lines
+    // which are trimmed by --context-before and --context-after respectively, and which are
+    // separated from the changes by the --syntax-padding lines.
+    pub hunk_has_changes: bool,
+    pub zero_lines: Vec<String>,
+    // If the current file is added or deleted, and --file-added-line-style or
+    // --file-deleted-line-style respectively is set, the style replacing plus-style or
+    // minus-style for its lines.
+    pub file_line_style: Option<Style>,
 }
 
 // How the background of a line is filled up to the end
@@ -40,6 +57,9 @@ pub enum BgFillMethod {
     // also see `UseFullPanelWidth`
     TryAnsiSequence,
     Spaces,
+    // Fill with the given character, which has a display width of 1 or 2. Unlike the
+    // other methods this is visible even without a background color.
+    Pattern(char),
 }
 
 // If the background of a line extends to the end, and if configured to do so, how.
@@ -62,16 +82,25 @@ impl<'p> Painter<'p> {
         let panel_width_fix = ansifill::UseFullPanelWidth::new(config);
 
         let line_numbers_data = if config.line_numbers {
-            Some(line_numbers::LineNumbersData::from_format_strings(
-                &config.line_numbers_format,
-                panel_width_fix,
-            ))
+            Some(
+                line_numbers::LineNumbersData::from_format_strings(
+                    &config.line_numbers_format,
+                    panel_width_fix.clone(),
+                    config.side_by_side_direction,
+                )
+                .with_changed_line_formats(
+                    &config.line_numbers_format_minusplus,
+                    panel_width_fix,
+                    config.side_by_side_direction,
+                ),
+            )
         } else if config.side_by_side {
             // If line numbers are disabled in side-by-side then the data is still used
             // for width calculaction and to pad odd width to even, see `UseFullPanelWidth`
             // for details.
             Some(line_numbers::LineNumbersData::empty_for_sbs(
                 panel_width_fix,
+                config.side_by_side_direction,
             ))
         } else {
             None
@@ -82,14 +111,32 @@ impl<'p> Painter<'p> {
             output_buffer: String::new(),
             syntax: default_syntax,
             highlighter: None,
+            syntax_disabled: false,
             writer,
             config,
             line_numbers_data,
+            hunk_has_changes: false,
+            zero_lines: Vec::new(),
+            file_line_style: None,
         }
     }
 
     pub fn set_syntax(&mut self, extension: Option<&str>) {
-        self.syntax = Painter::get_syntax(&self.config.syntax_set, extension);
+        // With --syntax-ignore-case, an extension without an exact match is looked up in lower
+        // case.
+        let lowercase_extension = extension
+            .filter(|_| self.config.syntax_ignore_case)
+            .filter(|extension| {
+                self.config
+                    .syntax_set
+                    .find_syntax_by_extension(extension)
+                    .is_none()
+            })
+            .map(str::to_lowercase);
+        self.syntax = Painter::get_syntax(
+            &self.config.syntax_set,
+            lowercase_extension.as_deref().or(extension),
+        );
     }
 
     fn get_syntax<'a>(syntax_set: &'a SyntaxSet, extension: Option<&str>) -> &'a SyntaxReference {
@@ -104,8 +151,11 @@ impl<'p> Painter<'p> {
     }
 
     pub fn set_highlighter(&mut self) {
-        if let Some(ref syntax_theme) = self.config.syntax_theme {
-            self.highlighter = Some(HighlightLines::new(self.syntax, syntax_theme))
+        self.highlighter = match self.config.syntax_theme {
+            Some(ref syntax_theme) if !self.syntax_disabled => {
+                Some(HighlightLines::new(self.syntax, syntax_theme))
+            }
+            _ => None,
         };
     }
 
@@ -132,6 +182,16 @@ impl<'p> Painter<'p> {
         }
     }
 
+    /// Whether the content of hunk line `line`, i.e. the text after the +/-/space marker, matches
+    /// a --redact-lines pattern.
+    pub fn is_redacted(&self, line: &str) -> bool {
+        let content = line.get(1..).unwrap_or("");
+        self.config
+            .redact_lines
+            .iter()
+            .any(|regex| regex.is_match(content))
+    }
+
     /// Remove the initial +/- character of a line that will be emitted unchanged, including any
     /// ANSI escape sequences.
     pub fn prepare_raw_line(&self, line: &str) -> String {
@@ -173,15 +233,26 @@ impl<'p> Painter<'p> {
             self.highlighter.as_mut(),
             self.config,
         );
-        let (minus_line_diff_style_sections, plus_line_diff_style_sections, line_alignment) =
+        let (mut minus_line_diff_style_sections, mut plus_line_diff_style_sections, line_alignment) =
             Self::get_diff_style_sections(&self.minus_lines, &self.plus_lines, self.config);
+        if let Some(file_line_style) = self.file_line_style {
+            for (style, _) in minus_line_diff_style_sections
+                .iter_mut()
+                .chain(plus_line_diff_style_sections.iter_mut())
+                .flatten()
+            {
+                if *style == self.config.minus_style || *style == self.config.plus_style {
+                    *style = file_line_style;
+                }
+            }
+        }
 
         if self.config.side_by_side {
-            let syntax_left_right = MinusPlus::new(
+            let mut syntax_left_right = MinusPlus::new(
                 minus_line_syntax_style_sections,
                 plus_line_syntax_style_sections,
             );
-            let diff_left_right = MinusPlus::new(
+            let mut diff_left_right = MinusPlus::new(
                 minus_line_diff_style_sections,
                 plus_line_diff_style_sections,
             );
@@ -203,7 +274,10 @@ impl<'p> Painter<'p> {
 
             let bg_fill_left_right = MinusPlus::new(
                 // Using an ANSI sequence to fill the left panel would not work.
-                BgShouldFill::With(BgFillMethod::Spaces),
+                match self.config.line_fill_method {
+                    BgFillMethod::Pattern(c) => BgShouldFill::With(BgFillMethod::Pattern(c)),
+                    _ => BgShouldFill::With(BgFillMethod::Spaces),
+                },
                 // Use what is configured for the right side.
                 BgShouldFill::With(self.config.line_fill_method),
             );
@@ -211,7 +285,8 @@ impl<'p> Painter<'p> {
             // Only set `should_wrap` to true if wrapping is wanted and lines which are
             // too long are found.
             // If so, remember the calculated line width and which of the lines are too
-            // long for later re-use.
+            // long for later re-use. Most blocks have no long lines, so check for that
+            // first before recording which of the lines are too long.
             let (should_wrap, line_width, long_lines) = {
                 if self.config.wrap_config.max_lines == 1 {
                     (false, MinusPlus::default(), MinusPlus::default())
@@ -220,31 +295,43 @@ impl<'p> Painter<'p> {
 
                     let lines = MinusPlus::new(&self.minus_lines, &self.plus_lines);
 
-                    let (should_wrap, long_lines) =
-                        side_by_side::has_long_lines(&lines, &line_width);
-
-                    (should_wrap, line_width, long_lines)
+                    if side_by_side::has_any_long_line(&lines, &line_width) {
+                        let (should_wrap, long_lines) =
+                            side_by_side::has_long_lines(&lines, &line_width);
+                        (should_wrap, line_width, long_lines)
+                    } else {
+                        (false, line_width, MinusPlus::default())
+                    }
                 }
             };
 
-            let (line_alignment, line_states, syntax_left_right, diff_left_right) = if should_wrap {
-                // Calculated for syntect::highlighting::style::Style and delta::Style
-                wrap_minusplus_block(
+            // Calculated for syntect::highlighting::style::Style and delta::Style. If the lines
+            // cannot be wrapped, they are left in place and painted without wrapping.
+            let wrapped = if should_wrap {
+                match wrap_minusplus_block(
                     self.config,
-                    syntax_left_right,
-                    diff_left_right,
+                    &mut syntax_left_right,
+                    &mut diff_left_right,
                     &line_alignment,
                     &line_width,
                     &long_lines,
-                )
+                ) {
+                    Ok(wrapped) => Some(wrapped),
+                    Err(error) => {
+                        eprintln!("delta: failed to wrap lines: {}", error);
+                        None
+                    }
+                }
             } else {
-                (
+                None
+            };
+            let (line_alignment, line_states, syntax_left_right, diff_left_right) = wrapped
+                .unwrap_or((
                     line_alignment,
                     states_left_right,
                     syntax_left_right,
                     diff_left_right,
-                )
-            };
+                ));
 
             side_by_side::paint_minus_and_plus_lines_side_by_side(
                 syntax_left_right,
@@ -298,6 +385,129 @@ impl<'p> Painter<'p> {
         self.plus_lines.clear();
     }
 
+    /// Paint everything buffered at the end of a hunk, including the --syntax-padding lines
+    /// following its last change, and the unchanged lines allowed by --context-after.
+    pub fn paint_buffered_lines_at_hunk_end(&mut self) {
+        self.paint_buffered_minus_and_plus_lines();
+        if self.hunk_has_changes {
+            self.paint_syntax_padding_lines();
+            let mut zero_lines = std::mem::take(&mut self.zero_lines);
+            if let Some(context_after) = self.config.context_after {
+                zero_lines.truncate(context_after);
+            }
+            for line in zero_lines {
+                self.paint_zero_line(&line);
+            }
+            self.hunk_has_changes = false;
+        } else {
+            self.paint_buffered_zero_lines();
+        }
+    }
+
+    /// Called before buffering a changed line. If this is the first change of the hunk, paint the
+    /// unchanged lines allowed by --context-before, followed by the --syntax-padding lines;
+    /// otherwise paint the unchanged lines held back since the last change.
+    pub fn paint_lines_before_change(&mut self) {
+        if !self.holds_back_zero_lines() {
+            return;
+        }
+        if self.hunk_has_changes {
+            self.paint_buffered_zero_lines();
+        } else {
+            let mut zero_lines = std::mem::take(&mut self.zero_lines);
+            if let Some(context_before) = self.config.context_before {
+                let n_skipped = zero_lines.len().saturating_sub(context_before);
+                zero_lines.drain(..n_skipped);
+                self.skip_zero_lines(n_skipped);
+            }
+            for line in zero_lines {
+                self.paint_zero_line(&line);
+            }
+            self.paint_syntax_padding_lines();
+            self.hunk_has_changes = true;
+        }
+    }
+
+    /// Paint an unchanged line, unless it has to be held back (see `zero_lines`).
+    pub fn paint_or_buffer_zero_line(&mut self, line: &str) {
+        if self.hunk_has_changes {
+            self.zero_lines.push(line.to_string());
+            // Like the minus and plus lines, the held back lines are painted once there are more
+            // than line-buffer-size of them; --context-after is then not applied to them.
+            if self.zero_lines.len() > self.config.line_buffer_size {
+                self.paint_buffered_zero_lines();
+            }
+        } else if let Some(context_before) = self.config.context_before {
+            // Only the last context-before lines preceding the first change are painted.
+            self.zero_lines.push(line.to_string());
+            if self.zero_lines.len() > context_before {
+                self.zero_lines.remove(0);
+                self.skip_zero_lines(1);
+            }
+        } else {
+            self.paint_zero_line(line);
+        }
+    }
+
+    fn holds_back_zero_lines(&self) -> bool {
+        self.config.syntax_padding > 0
+            || self.config.context_before.is_some()
+            || self.config.context_after.is_some()
+    }
+
+    /// Account for unchanged lines which are not displayed, so that the line numbers of the
+    /// following lines remain correct.
+    fn skip_zero_lines(&mut self, n: usize) {
+        if let Some(line_numbers_data) = self.line_numbers_data.as_mut() {
+            line_numbers_data.line_number[Minus] += n;
+            line_numbers_data.line_number[Plus] += n;
+            line_numbers_data.hunk_line_counter[Minus] += n;
+            line_numbers_data.hunk_line_counter[Plus] += n;
+        }
+    }
+
+    pub fn paint_buffered_zero_lines(&mut self) {
+        for line in std::mem::take(&mut self.zero_lines) {
+            self.paint_zero_line(&line);
+        }
+    }
+
+    /// Paint the blank lines requested by --syntax-padding. They are styled as unchanged lines
+    /// but, like the continuation of a wrapped line, their line number fields are empty.
+    fn paint_syntax_padding_lines(&mut self) {
+        let state = State::HunkZeroWrapped;
+        let line = " \n";
+        for _ in 0..self.config.syntax_padding {
+            let syntax_style_sections = vec![vec![(self.config.null_syntect_style, line)]];
+            let diff_style_sections = vec![vec![(self.config.zero_style, line)]];
+            if self.config.side_by_side {
+                side_by_side::paint_zero_lines_side_by_side(
+                    line,
+                    state.clone(),
+                    syntax_style_sections,
+                    diff_style_sections,
+                    &mut self.output_buffer,
+                    self.config,
+                    &mut self.line_numbers_data.as_mut(),
+                    None,
+                    BgShouldFill::With(BgFillMethod::Spaces),
+                );
+            } else {
+                Painter::paint_lines(
+                    syntax_style_sections,
+                    diff_style_sections,
+                    [state.clone()].iter(),
+                    &mut self.output_buffer,
+                    self.config,
+                    &mut self.line_numbers_data.as_mut(),
+                    None,
+                    None,
+                    BgShouldFill::With(BgFillMethod::Spaces),
+                );
+            }
+        }
+    }
+
     pub fn paint_zero_line(&mut self, line: &str) {
         let state = State::HunkZero;
         let painted_prefix = if self.config.keep_plus_minus_markers && !line.is_empty() {
@@ -305,19 +515,43 @@ impl<'p> Painter<'p> {
         } else {
             None
         };
-        let lines = vec![(self.prepare(line), state.clone())];
+        // A redacted line is painted in --redact-style and not passed to the syntax highlighter.
+        let is_redacted = self.is_redacted(line);
+        let (line, style) = if is_redacted {
+            (
+                format!(" {}", self.config.redact_placeholder),
+                self.config.redact_style,
+            )
+        } else {
+            (line.to_string(), self.config.zero_style)
+        };
+        let lines = vec![(self.prepare(&line), state.clone())];
         let syntax_style_sections = Painter::get_syntax_style_sections_for_lines(
             &lines,
             &state,
-            self.highlighter.as_mut(),
+            if is_redacted {
+                None
+            } else {
+                self.highlighter.as_mut()
+            },
             self.config,
         );
-        let diff_style_sections = vec![(self.config.zero_style, lines[0].0.as_str())]; // TODO: compute style from state
+        let diff_style_sections = vec![(style, lines[0].0.as_str())]; // TODO: compute style from state
+
+        // Shade the columns before the line is wrapped, so that the shading continues on the
+        // wrapped lines.
+        let diff_style_sections = match self.config.column_shades {
+            Some((n_columns, odd_style, even_style)) => {
+                style_column_shades(diff_style_sections, n_columns, odd_style, even_style)
+            }
+            None => diff_style_sections,
+        };
 
         if self.config.side_by_side {
             // `lines[0].0` so the line has the '\n' already added (as in the +- case)
             side_by_side::paint_zero_lines_side_by_side(
                 &lines[0].0,
+                state,
                 syntax_style_sections,
                 vec![diff_style_sections],
                 &mut self.output_buffer,
@@ -367,7 +601,7 @@ impl<'p> Painter<'p> {
                 .iter()
                 .zip_eq(diff_style_sections.iter()),
         ) {
-            let (mut line, line_is_empty) = Painter::paint_line(
+            let (mut line, line_is_empty, text_width) = Painter::paint_line(
                 syntax_sections,
                 diff_sections,
                 state,
@@ -387,7 +621,6 @@ impl<'p> Painter<'p> {
             if let Some(BgFillMethod::TryAnsiSequence) = bg_fill_mode {
                 Painter::right_fill_background_color(&mut line, fill_style);
             } else if let Some(BgFillMethod::Spaces) = bg_fill_mode {
-                let text_width = ansi::measure_text_width(&line);
                 line.push_str(
                     &fill_style
                         .paint(" ".repeat(config.available_terminal_width - text_width))
@@ -437,6 +670,30 @@ impl<'p> Painter<'p> {
         );
     }
 
+    /// Return the style requested by --minus-empty-line-style or --plus-empty-line-style if this
+    /// is a minus or plus line whose content is empty, i.e. nothing but the "+/-/ " prefix
+    /// and the newline.
+    fn get_empty_line_style(
+        diff_sections: &[(Style, &str)],
+        state: &State,
+        config: &config::Config,
+    ) -> Option<Style> {
+        let empty_line_style = match state {
+            State::HunkMinus(None) => config.minus_empty_line_style,
+            State::HunkPlus(None) => config.plus_empty_line_style,
+            _ => None,
+        }?;
+        let mut content = diff_sections
+            .iter()
+            .flat_map(|(_, text)| text.chars())
+            .skip(1);
+        if !diff_sections.is_empty() && content.all(|c| c == '\n') {
+            Some(empty_line_style)
+        } else {
+            None
+        }
+    }
+
     /// Determine whether the terminal should fill the line rightwards with a background color, and
     /// the style for doing so.
     pub fn get_should_right_fill_background_color_and_fill_style(
@@ -449,7 +706,10 @@ impl<'p> Painter<'p> {
         // non_emph_style: for right fill if line contains emph sections
         let (style, non_emph_style) = match state {
             State::HunkMinus(None) | State::HunkMinusWrapped => {
-                (config.minus_style, config.minus_non_emph_style)
+                match config.file_deleted_line_style {
+                    Some(style) if style_sections_are_all(diff_sections, style) => (style, style),
+                    _ => (config.minus_style, config.minus_non_emph_style),
+                }
             }
             State::HunkMinus(Some(raw_line)) => {
                 // TODO: This is the second time we are parsing the ANSI sequences
@@ -464,9 +724,10 @@ impl<'p> Painter<'p> {
                 }
             }
             State::HunkZero | State::HunkZeroWrapped => (config.zero_style, config.zero_style),
-            State::HunkPlus(None) | State::HunkPlusWrapped => {
-                (config.plus_style, config.plus_non_emph_style)
-            }
+            State::HunkPlus(None) | State::HunkPlusWrapped => match config.file_added_line_style {
+                Some(style) if style_sections_are_all(diff_sections, style) => (style, style),
+                _ => (config.plus_style, config.plus_non_emph_style),
+            },
             State::HunkPlus(Some(raw_line)) => {
                 // TODO: This is the second time we are parsing the ANSI sequences
                 if let Some(ansi_term_style) = ansi::parse_first_style(raw_line) {
@@ -481,7 +742,11 @@ impl<'p> Painter<'p> {
             }
             _ => (config.null_style, config.null_style),
         };
-        let fill_style = if style_sections_contain_more_than_one_style(diff_sections) {
+        let fill_style = if let Some(empty_line_style) =
+            Painter::get_empty_line_style(diff_sections, state, config)
+        {
+            empty_line_style
+        } else if style_sections_contain_more_than_one_style(diff_sections) {
             non_emph_style // line contains an emph section
         } else {
             style
@@ -529,7 +794,8 @@ impl<'p> Painter<'p> {
         );
     }
 
-    /// Return painted line (maybe prefixed with line numbers field) and an is_empty? boolean.
+    /// Return painted line (maybe prefixed with line numbers field), an is_empty? boolean, and the
+    /// display width of the painted line.
     pub fn paint_line(
         syntax_sections: &[(SyntectStyle, &str)],
         diff_sections: &[(Style, &str)],
@@ -538,9 +804,10 @@ impl<'p> Painter<'p> {
         side_by_side_panel: Option<PanelSide>,
         painted_prefix: Option<ansi_term::ANSIString>,
         config: &config::Config,
-    ) -> (String, bool) {
+    ) -> (String, bool, usize) {
         let mut ansi_strings = Vec::new();
 
+        let mut is_in_line_range = false;
         let output_line_numbers = line_numbers_data.is_some();
         if output_line_numbers {
             // Unified diff lines are printed in one go, but side-by-side lines
@@ -553,8 +820,14 @@ impl<'p> Painter<'p> {
                 config,
                 increment,
             ) {
+                if let Some((start, end)) = config.syntax_line_range {
+                    is_in_line_range = line_numbers[Plus]
+                        .or(line_numbers[Minus])
+                        .map_or(false, |n| start <= n && n <= end);
+                }
                 ansi_strings.extend(line_numbers::format_and_paint_line_numbers(
                     line_numbers_data.as_ref().unwrap(),
+                    state,
                     side_by_side_panel,
                     styles,
                     line_numbers,
@@ -562,6 +835,15 @@ impl<'p> Painter<'p> {
                 ))
             }
         }
+        // Lines outside of --syntax-line-range are indented by the width of the marker, so that
+        // all lines stay aligned.
+        if config.syntax_line_range.is_some() && config.line_numbers {
+            ansi_strings.push(if is_in_line_range {
+                config.line_range_style.paint(LINE_RANGE_MARKER)
+            } else {
+                config.null_style.paint(" ")
+            });
+        }
         match state {
             State::HunkMinus(Some(raw_line)) | State::HunkPlus(Some(raw_line)) => {
                 // This line has been identified as one which should be emitted unchanged,
@@ -573,6 +855,8 @@ impl<'p> Painter<'p> {
                         raw_line
                     ),
                     false,
+                    Painter::measure_ansi_strings_width(&ansi_strings)
+                        + ansi::measure_text_width(raw_line),
                 );
             }
             _ => {}
@@ -584,9 +868,22 @@ impl<'p> Painter<'p> {
             config.true_color,
             config.null_syntect_style,
         );
+        let marker = match state {
+            State::HunkMinus(_) | State::HunkMinusWrapped => Some('-'),
+            State::HunkPlus(_) | State::HunkPlusWrapped => Some('+'),
+            State::HunkZero | State::HunkZeroWrapped => Some(' '),
+            _ => None,
+        };
+        let superimposed = if marker.is_some() && delta::shows_line_ending(marker, config) {
+            style_line_ending_symbol(superimposed, config.line_ending_style)
+        } else {
+            superimposed
+        };
 
+        let empty_line_style = Painter::get_empty_line_style(diff_sections, state, config);
         let mut handled_prefix = false;
         for (section_style, text) in &superimposed {
+            let section_style = empty_line_style.as_ref().unwrap_or(section_style);
             let text = if handled_prefix {
                 &text
             } else {
@@ -611,7 +908,21 @@ impl<'p> Painter<'p> {
 
         // Only if syntax is empty (implies diff empty) can a line actually be empty.
         let is_empty = syntax_sections.is_empty();
-        (ansi_term::ANSIStrings(&ansi_strings).to_string(), is_empty)
+        (
+            ansi_term::ANSIStrings(&ansi_strings).to_string(),
+            is_empty,
+            Painter::measure_ansi_strings_width(&ansi_strings),
+        )
+    }
+
+    /// The display width of the concatenation of `ansi_strings`. Only their text is measured,
+    /// rather than the painted string, which is longer by the ANSI escape sequences of the styles.
+    // The text can still contain escape sequences, e.g. line numbers with OSC 8 hyperlinks.
+    fn measure_ansi_strings_width(ansi_strings: &[ansi_term::ANSIString]) -> usize {
+        ansi_strings
+            .iter()
+            .map(|ansi_string| ansi::measure_text_width(ansi_string))
+            .sum()
     }
 
     /// Write output buffer to output stream, and clear the buffer.
@@ -773,6 +1084,103 @@ fn style_sections_contain_more_than_one_style(sections: &[(Style, &str)]) -> boo
     }
 }
 
+/// Whether all sections are painted in `style`, e.g. the lines of an added or deleted file (see
+/// `Painter::file_line_style`).
+fn style_sections_are_all(sections: &[(Style, &str)], style: Style) -> bool {
+    !sections.is_empty() && sections.iter().all(|(s, _)| *s == style)
+}
+
+/// Split sections such that the line ending symbol appended by --show-line-endings, i.e. the end
+/// of the line before the final newline, is painted with line-ending-style. Symbols which are part
+/// of the text of the line keep their style.
+fn style_line_ending_symbol(
+    sections: Vec<(Style, String)>,
+    line_ending_style: Style,
+) -> Vec<(Style, String)> {
+    let text = sections.iter().map(|(_, s)| s.as_str()).collect::<String>();
+    let text = text.strip_suffix('\n').unwrap_or(&text);
+    let symbol_len = if text.ends_with(delta::CRLF_SYMBOL) {
+        delta::CRLF_SYMBOL.len()
+    } else if text.ends_with(delta::LF_SYMBOL) {
+        delta::LF_SYMBOL.len()
+    } else {
+        // The end of a line which has been wrapped.
+        return sections;
+    };
+    let (symbol_start, symbol_end) = (text.len() - symbol_len, text.len());
+    let mut styled = Vec::new();
+    let mut offset = 0;
+    for (style, text) in sections {
+        let start = symbol_start.max(offset).min(offset + text.len()) - offset;
+        let end = symbol_end.max(offset).min(offset + text.len()) - offset;
+        offset += text.len();
+        if start == end {
+            styled.push((style, text));
+            continue;
+        }
+        if start > 0 {
+            styled.push((style, text[..start].to_string()));
+        }
+        styled.push((line_ending_style, text[start..end].to_string()));
+        if end < text.len() {
+            styled.push((style, text[end..].to_string()));
+        }
+    }
+    styled
+}
+
+/// Split sections into groups of `n_columns` display columns and give the groups the background
+/// color of `odd_style` and `even_style` in turn (see --syntax-wrap-color-columns). The first
+/// character stands in for the +/- prefix (see `prepare()`) and is not counted as a column.
+fn style_column_shades<'a>(
+    sections: LineSegments<'a, Style>,
+    n_columns: usize,
+    odd_style: Style,
+    even_style: Style,
+) -> LineSegments<'a, Style> {
+    let shade = |style: Style, column: usize| {
+        let shade_style = if (column / n_columns) % 2 == 0 {
+            odd_style
+        } else {
+            even_style
+        };
+        match shade_style.ansi_term_style.background {
+            Some(background) => Style {
+                ansi_term_style: ansi_term::Style {
+                    background: Some(background),
+                    ..style.ansi_term_style
+                },
+                ..style
+            },
+            None => style,
+        }
+    };
+    let mut styled = Vec::new();
+    let mut column = None;
+    for (style, text) in sections {
+        // `text[start..]` is styled with `start_style`, up to where the style changes.
+        let mut start = 0;
+        let mut start_style = style;
+        for (i, c) in text.char_indices() {
+            let char_style = match column {
+                Some(column) if c != '\n' => shade(style, column),
+                _ => style,
+            };
+            if char_style != start_style {
+                if i > start {
+                    styled.push((start_style, &text[start..i]));
+                }
+                start = i;
+                start_style = char_style;
+            }
+            column =
+                Some(column.map_or(0, |column| column + UnicodeWidthChar::width(c).unwrap_or(0)));
+        }
+        styled.push((start_style, &text[start..]));
+    }
+    styled
+}
+
 /// True iff the line represented by `sections` constitutes a whitespace error.
 // Note that a space is always present as the first character in the line (it was put there as a
 // replacement for the leading +/- marker; see paint::prepare()). A line is a whitespace error iff,
@@ -1015,3 +1423,80 @@ mod superimpose_style_sections {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{style_column_shades, style_line_ending_symbol, Painter};
+    use crate::ansi::strip_ansi_codes;
+    use crate::style::Style;
+    use crate::tests::integration_test_utils::{make_config_from_args, run_delta};
+
+    #[test]
+    fn test_set_syntax_ignore_case() {
+        let config = make_config_from_args(&["--syntax-ignore-case"]);
+        let mut writer = Vec::<u8>::new();
+        let mut painter = Painter::new(&mut writer, &config);
+        painter.set_syntax(Some("RS"));
+        assert_eq!(painter.syntax.name, "Rust");
+        painter.set_syntax(Some("not-an-extension"));
+        assert_eq!(painter.syntax.name, "Plain Text");
+    }
+
+    #[test]
+    fn test_syntax_line_range() {
+        let config = make_config_from_args(&["--line-numbers", "--syntax-line-range", "2-3"]);
+        let output = strip_ansi_codes(&run_delta(DIFF, &config));
+        for line in &["│ a\n", "│▎b\n", "│▎c\n", "│▎C\n", "│ d\n"] {
+            assert!(output.contains(line), "{:?} not in {:?}", line, output);
+        }
+
+        // Without line numbers, the option has no effect.
+        let config = make_config_from_args(&["--syntax-line-range", "2-3"]);
+        let output = strip_ansi_codes(&run_delta(DIFF, &config));
+        for line in &["\na\n", "\nb\n", "\nc\n", "\nC\n", "\nd\n"] {
+            assert!(output.contains(line), "{:?} not in {:?}", line, output);
+        }
+    }
+
+    #[test]
+    fn test_style_line_ending_symbol() {
+        let style = Style::from_str("red", None, None, true, false);
+        let line_ending_style = Style::from_str("blue", None, None, true, false);
+        let sections = vec![(style, " a↵b↵".to_string()), (style, "⬛\n".to_string())];
+        assert_eq!(
+            style_line_ending_symbol(sections, line_ending_style),
+            vec![
+                (style, " a↵b".to_string()),
+                (line_ending_style, "↵".to_string()),
+                (line_ending_style, "⬛".to_string()),
+                (style, "\n".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_style_column_shades() {
+        let style = Style::from_str("red", None, None, true, false);
+        let odd = Style::from_str("normal", None, None, true, false);
+        let even = Style::from_str("normal blue", None, None, true, false);
+        let shaded = Style::from_str("red blue", None, None, true, false);
+        let sections = vec![(style, " a"), (style, "bcde\n")];
+        assert_eq!(
+            style_column_shades(sections, 2, odd, even),
+            vec![(style, " a"), (style, "b"), (shaded, "cd"), (style, "e\n"),]
+        );
+    }
+
+    const DIFF: &str = "\
+diff --git a/a.txt b/a.txt
+index 7898192..6178079 100644
+--- a/a.txt
++++ b/a.txt
+@@ -1,4 +1,4 @@
+ a
+ b
+-c
++C
+ d
+";
+}
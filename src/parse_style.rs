@@ -310,10 +310,16 @@ fn _extract_special_decoration_attributes(
     {
         match token {
             "box" => attributes |= DecorationAttributes::BOX,
-            token if token == "overline" || is_decoration_style_string && token == "ol" => {
+            token
+                if token == "overline"
+                    || is_decoration_style_string && (token == "ol" || token == "top-line") =>
+            {
                 attributes |= DecorationAttributes::OVERLINE
             }
-            token if token == "underline" || is_decoration_style_string && token == "ul" => {
+            token
+                if token == "underline"
+                    || is_decoration_style_string && (token == "ul" || token == "bottom-line") =>
+            {
                 attributes |= DecorationAttributes::UNDERLINE
             }
             token if token == "none" || token == "plain" => {}
@@ -502,6 +508,22 @@ mod tests {
             extract_special_decoration_attributes("ol ul"),
             (UL | OL, "".to_string())
         );
+        assert_eq!(
+            extract_special_decoration_attributes("top-line"),
+            (OL, "".to_string())
+        );
+        assert_eq!(
+            extract_special_decoration_attributes("bottom-line"),
+            (UL, "".to_string())
+        );
+        assert_eq!(
+            extract_special_decoration_attributes("top-line bottom-line"),
+            (UL | OL, "".to_string())
+        );
+        assert_eq!(
+            extract_special_decoration_attributes_from_non_decoration_style_string("top-line"),
+            (EMPTY, "top-line".to_string())
+        );
     }
 
     #[test]
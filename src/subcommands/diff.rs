@@ -1,11 +1,11 @@
+use std::ffi::OsString;
 use std::io::{ErrorKind, Write};
 use std::path::PathBuf;
 use std::process;
 
-use bytelines::ByteLinesReader;
-
 use crate::config::{self, delta_unreachable};
 use crate::delta;
+use crate::env;
 
 /// Run `git diff` on the files provided on the command line and display the output.
 pub fn diff(
@@ -14,7 +14,6 @@ pub fn diff(
     config: &config::Config,
     writer: &mut dyn Write,
 ) -> i32 {
-    use std::io::BufReader;
     if minus_file.is_none() || plus_file.is_none() {
         eprintln!(
             "\
@@ -27,6 +26,122 @@ You can also use delta to diff two files: `delta file_A file_B`."
     let minus_file = minus_file.unwrap();
     let plus_file = plus_file.unwrap();
 
+    let mut args = git_config_parameter_args(config);
+    args.extend(vec!["diff".into(), "--no-index".into()]);
+    args.extend(vec![minus_file.into(), plus_file.into()]);
+    run_git(&args, config, writer)
+}
+
+/// If delta is running as git's pager and --git-config-parameters was given, re-run the git
+/// command that invoked delta with the extra `-c KEY=VALUE` settings, and display its output
+/// instead of standard input. Return None if this does not apply, if the calling git command
+/// cannot be determined, or if it may have side effects and must not be run a second time.
+pub fn rerun_calling_git_command(config: &config::Config, writer: &mut dyn Write) -> Option<i32> {
+    if config.git_config_parameters.is_empty() || !env::get_boolean_env_var("GIT_PAGER_IN_USE") {
+        return None;
+    }
+    let calling_git_args = match get_calling_git_args() {
+        Some(calling_git_args) => calling_git_args,
+        None => {
+            eprintln!(
+                "delta: could not determine the calling git command: \
+                 ignoring --git-config-parameters."
+            );
+            return None;
+        }
+    };
+    if !is_read_only_diff_command(&calling_git_args) {
+        eprintln!(
+            "delta: the calling git command is not known to be read-only: \
+             ignoring --git-config-parameters."
+        );
+        return None;
+    }
+    // The child inherits GIT_PAGER_IN_USE, so git colors its output as it did for delta's stdin.
+    let mut args = vec!["--no-pager".into()];
+    args.extend(git_config_parameter_args(config));
+    args.extend(calling_git_args);
+    Some(run_git(&args, config, writer))
+}
+
+/// Git subcommands which only display diffs, so that running them a second time has no side
+/// effects.
+const READ_ONLY_DIFF_SUBCOMMANDS: &[&str] = &[
+    "diff",
+    "diff-files",
+    "diff-index",
+    "diff-tree",
+    "log",
+    "range-diff",
+    "show",
+    "whatchanged",
+];
+
+/// Whether the git command with arguments `args` (without argv[0]) is one of the
+/// [`READ_ONLY_DIFF_SUBCOMMANDS`] or `stash show`. The subcommand is the first argument after
+/// git's global options.
+fn is_read_only_diff_command(args: &[OsString]) -> bool {
+    let mut args = args.iter().map(|arg| arg.to_str());
+    while let Some(arg) = args.next() {
+        match arg {
+            // Global options which take their value as a separate argument.
+            Some("-C")
+            | Some("-c")
+            | Some("--git-dir")
+            | Some("--work-tree")
+            | Some("--namespace")
+            | Some("--config-env")
+            | Some("--super-prefix") => {
+                args.next();
+            }
+            Some(arg) if arg.starts_with('-') => {}
+            Some("stash") => return args.next() == Some(Some("show")),
+            Some(subcommand) => return READ_ONLY_DIFF_SUBCOMMANDS.contains(&subcommand),
+            None => return false,
+        }
+    }
+    false
+}
+
+fn git_config_parameter_args(config: &config::Config) -> Vec<OsString> {
+    config
+        .git_config_parameters
+        .iter()
+        .flat_map(|param| vec!["-c".into(), param.into()])
+        .collect()
+}
+
+/// Return the arguments (without argv[0]) of the parent process, if it is git.
+#[cfg(target_os = "linux")]
+fn get_calling_git_args() -> Option<Vec<OsString>> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    let parent_id = std::os::unix::process::parent_id();
+    let mut cmdline = std::fs::read(format!("/proc/{}/cmdline", parent_id)).ok()?;
+    if cmdline.last() == Some(&0) {
+        cmdline.pop();
+    }
+    let mut args = cmdline
+        .split(|byte| *byte == 0)
+        .map(|arg| OsStr::from_bytes(arg).to_os_string());
+    let program = args.next()?;
+    if Path::new(&program).file_name()? != OsStr::new("git") {
+        return None;
+    }
+    Some(args.collect())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_calling_git_args() -> Option<Vec<OsString>> {
+    None
+}
+
+/// Run git with `args` and display the output, returning git's exit code.
+fn run_git(args: &[OsString], config: &config::Config, writer: &mut dyn Write) -> i32 {
+    use std::io::BufReader;
+
     let diff_command = "git";
     let diff_command_path = match grep_cli::resolve_binary(PathBuf::from(diff_command)) {
         Ok(path) => path,
@@ -37,8 +152,7 @@ You can also use delta to diff two files: `delta file_A file_B`."
     };
 
     let diff_process = process::Command::new(diff_command_path)
-        .args(&["diff", "--no-index"])
-        .args(&[minus_file, plus_file])
+        .args(args)
         .stdout(process::Stdio::piped())
         .spawn();
 
@@ -49,7 +163,7 @@ You can also use delta to diff two files: `delta file_A file_B`."
     let mut diff_process = diff_process.unwrap();
 
     if let Err(error) = delta::delta(
-        BufReader::new(diff_process.stdout.take().unwrap()).byte_lines(),
+        BufReader::new(diff_process.stdout.take().unwrap()),
         writer,
         config,
     ) {
@@ -79,10 +193,11 @@ You can also use delta to diff two files: `delta file_A file_B`."
 
 #[cfg(test)]
 mod main_tests {
+    use std::ffi::OsString;
     use std::io::{Cursor, Read, Seek, SeekFrom};
     use std::path::PathBuf;
 
-    use super::diff;
+    use super::{diff, is_read_only_diff_command};
     use crate::tests::integration_test_utils;
 
     #[test]
@@ -109,6 +224,40 @@ mod main_tests {
         _do_diff_test("/etc/group", "/etc/passwd", true);
     }
 
+    #[test]
+    fn test_is_read_only_diff_command() {
+        let is_read_only = |args: &[&str]| {
+            is_read_only_diff_command(&args.iter().map(OsString::from).collect::<Vec<_>>())
+        };
+        assert!(is_read_only(&["diff"]));
+        assert!(is_read_only(&["-p", "log", "-p"]));
+        assert!(is_read_only(&[
+            "-C",
+            "repo",
+            "-c",
+            "diff.renames=true",
+            "show",
+            "HEAD"
+        ]));
+        assert!(is_read_only(&[
+            "--git-dir=.git",
+            "--no-replace-objects",
+            "diff-tree",
+            "-p"
+        ]));
+        assert!(is_read_only(&["--paginate", "stash", "show", "-p"]));
+
+        assert!(!is_read_only(&[]));
+        assert!(!is_read_only(&["-p"]));
+        assert!(!is_read_only(&["-p", "stash", "pop"]));
+        assert!(!is_read_only(&["stash"]));
+        assert!(!is_read_only(&["-p", "am", "patch"]));
+        assert!(!is_read_only(&["commit", "-v"]));
+        // The value of a global option is not taken for the subcommand.
+        assert!(!is_read_only(&["-C", "diff", "apply"]));
+        assert!(!is_read_only(&["-c", "log", "add", "-p"]));
+    }
+
     fn _do_diff_test(file_a: &str, file_b: &str, expect_diff: bool) {
         let config = integration_test_utils::make_config_from_args(&[]);
         let mut writer = Cursor::new(vec![]);
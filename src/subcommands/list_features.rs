@@ -0,0 +1,87 @@
+use std::io::{self, Write};
+
+use itertools::Itertools;
+use unicode_width::UnicodeWidthStr;
+
+use crate::cli;
+use crate::features;
+use crate::options::option_value::{OptionValue, ProvenancedOptionValue};
+
+#[cfg(not(tarpaulin_include))]
+pub fn list_features(opt: &cli::Opt) -> std::io::Result<()> {
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    _list_features(opt, &mut stdout)
+}
+
+/// Write each builtin feature with its description, followed by the values of the options it
+/// sets. Values which depend on other options are computed from `opt`.
+pub fn _list_features(opt: &cli::Opt, writer: &mut dyn Write) -> std::io::Result<()> {
+    let builtin_features = features::make_builtin_features();
+    let descriptions = features::builtin_feature_descriptions();
+    let name_width = descriptions
+        .iter()
+        .map(|(name, _)| name.width())
+        .max()
+        .unwrap_or(0);
+    for (i, (name, description)) in descriptions.iter().enumerate() {
+        if i > 0 {
+            writeln!(writer)?;
+        }
+        writeln!(
+            writer,
+            "{}{}  {}",
+            name,
+            " ".repeat(name_width - name.width()),
+            description
+        )?;
+        for (option_name, value_function) in builtin_features[*name]
+            .iter()
+            .sorted_by_key(|(option_name, _)| *option_name)
+        {
+            writeln!(
+                writer,
+                "    {} = {}",
+                option_name,
+                format_option_value(value_function(opt, &None))
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn format_option_value(value: ProvenancedOptionValue) -> String {
+    let value = match value {
+        ProvenancedOptionValue::GitConfigValue(value) => value,
+        ProvenancedOptionValue::DefaultValue(value) => value,
+    };
+    match value {
+        OptionValue::Boolean(value) => value.to_string(),
+        OptionValue::Float(value) => value.to_string(),
+        OptionValue::OptionString(Some(value)) | OptionValue::String(value) => value,
+        OptionValue::OptionString(None) => "".to_string(),
+        OptionValue::Int(value) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::integration_test_utils;
+
+    #[test]
+    fn test_list_features() {
+        let opt = integration_test_utils::make_options_from_args(&[]);
+        let mut writer = Vec::new();
+        _list_features(&opt, &mut writer).unwrap();
+        let s = String::from_utf8(writer).unwrap();
+        for (name, description) in features::builtin_feature_descriptions() {
+            assert!(s
+                .lines()
+                .any(|line| line.starts_with(name) && line.ends_with(description)));
+        }
+        assert!(s.contains("\nside-by-side    Display"));
+        assert!(s.contains("\n    side-by-side = true\n"));
+        assert!(s.contains("\n    line-numbers-left-format = │{nm:^4}│\n"));
+    }
+}
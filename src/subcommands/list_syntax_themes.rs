@@ -3,21 +3,35 @@ use std::io::{self, Write};
 use itertools::Itertools;
 
 use crate::bat_utils::assets::HighlightingAssets;
-use crate::options::theme::is_light_syntax_theme;
+use crate::cli;
+use crate::options::theme::{add_themes_from_dir, is_light_syntax_theme};
 
 #[cfg(not(tarpaulin_include))]
-pub fn list_syntax_themes() -> std::io::Result<()> {
+pub fn list_syntax_themes(opt: &cli::Opt) -> std::io::Result<()> {
     let stdout = io::stdout();
     let mut stdout = stdout.lock();
+    let themes_dir = opt.themes_dir.as_deref();
     if atty::is(atty::Stream::Stdout) {
-        _list_syntax_themes_for_humans(&mut stdout)
+        _list_syntax_themes_for_humans(&mut stdout, themes_dir)
     } else {
-        _list_syntax_themes_for_machines(&mut stdout)
+        _list_syntax_themes_for_machines(&mut stdout, themes_dir)
     }
 }
 
-pub fn _list_syntax_themes_for_humans(writer: &mut dyn Write) -> std::io::Result<()> {
-    let assets = HighlightingAssets::new();
+/// The built-in assets, with the syntax themes of --themes-dir added.
+fn get_assets(themes_dir: Option<&str>) -> HighlightingAssets {
+    let mut assets = HighlightingAssets::new();
+    if let Some(themes_dir) = themes_dir {
+        add_themes_from_dir(&mut assets.theme_set, themes_dir);
+    }
+    assets
+}
+
+pub fn _list_syntax_themes_for_humans(
+    writer: &mut dyn Write,
+    themes_dir: Option<&str>,
+) -> std::io::Result<()> {
+    let assets = get_assets(themes_dir);
     let themes = &assets.theme_set.themes;
 
     writeln!(writer, "Light syntax themes:")?;
@@ -35,8 +49,11 @@ pub fn _list_syntax_themes_for_humans(writer: &mut dyn Write) -> std::io::Result
     Ok(())
 }
 
-pub fn _list_syntax_themes_for_machines(writer: &mut dyn Write) -> std::io::Result<()> {
-    let assets = HighlightingAssets::new();
+pub fn _list_syntax_themes_for_machines(
+    writer: &mut dyn Write,
+    themes_dir: Option<&str>,
+) -> std::io::Result<()> {
+    let assets = get_assets(themes_dir);
     let themes = &assets.theme_set.themes;
     for (theme, _) in themes
         .iter()
@@ -65,7 +82,7 @@ mod tests {
     #[test]
     fn test_list_syntax_themes_for_humans() {
         let mut writer = Cursor::new(vec![0; 512]);
-        _list_syntax_themes_for_humans(&mut writer).unwrap();
+        _list_syntax_themes_for_humans(&mut writer, None).unwrap();
         let mut s = String::new();
         writer.seek(SeekFrom::Start(0)).unwrap();
         writer.read_to_string(&mut s).unwrap();
@@ -78,7 +95,7 @@ mod tests {
     #[test]
     fn test_list_syntax_themes_for_machines() {
         let mut writer = Cursor::new(vec![0; 512]);
-        _list_syntax_themes_for_machines(&mut writer).unwrap();
+        _list_syntax_themes_for_machines(&mut writer, None).unwrap();
         let mut s = String::new();
         writer.seek(SeekFrom::Start(0)).unwrap();
         writer.read_to_string(&mut s).unwrap();
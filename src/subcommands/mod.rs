@@ -1,4 +1,5 @@
 pub mod diff;
+pub mod list_features;
 pub mod list_syntax_themes;
 mod sample_diff;
 pub mod show_config;
@@ -117,8 +117,9 @@ pub fn show_config(config: &config::Config, writer: &mut dyn Write) -> std::io::
         max_line_distance = config.max_line_distance,
         max_line_length = config.max_line_length,
         line_fill_method = match config.line_fill_method {
-            BgFillMethod::TryAnsiSequence => "ansi",
-            BgFillMethod::Spaces => "spaces",
+            BgFillMethod::TryAnsiSequence => "ansi".to_string(),
+            BgFillMethod::Spaces => "spaces".to_string(),
+            BgFillMethod::Pattern(c) => format!("pattern:{}", c),
         },
         navigate = config.navigate,
         navigate_regexp = match &config.navigate_regexp {
@@ -56,7 +56,6 @@ fn _show_syntax_themes(
     writer: &mut dyn Write,
     stdin: Option<&Vec<u8>>,
 ) -> std::io::Result<()> {
-    use bytelines::ByteLines;
     use std::io::BufReader;
     let input = match stdin {
         Some(stdin_data) => &stdin_data[..],
@@ -97,9 +96,7 @@ index f38589a..0f1bb83 100644
             title_style.paint(syntax_theme)
         )?;
         config.syntax_theme = Some(assets.theme_set.themes[syntax_theme.as_str()].clone());
-        if let Err(error) =
-            delta::delta(ByteLines::new(BufReader::new(&input[0..])), writer, &config)
-        {
+        if let Err(error) = delta::delta(BufReader::new(&input[0..]), writer, &config) {
             match error.kind() {
                 ErrorKind::BrokenPipe => std::process::exit(0),
                 _ => eprintln!("{}", error),
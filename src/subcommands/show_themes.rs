@@ -10,8 +10,6 @@ use crate::options::get::get_themes;
 pub fn show_themes(dark: bool, light: bool, computed_theme_is_light: bool) -> std::io::Result<()> {
     use std::io::BufReader;
 
-    use bytelines::ByteLines;
-
     use super::sample_diff::DIFF;
 
     let mut input = DIFF.to_vec();
@@ -45,9 +43,7 @@ pub fn show_themes(dark: bool, light: bool, computed_theme_is_light: bool) -> st
         {
             writeln!(writer, "\n\nTheme: {}\n", title_style.paint(theme))?;
 
-            if let Err(error) =
-                delta::delta(ByteLines::new(BufReader::new(&input[0..])), writer, &config)
-            {
+            if let Err(error) = delta::delta(BufReader::new(&input[0..]), writer, &config) {
                 match error.kind() {
                     ErrorKind::BrokenPipe => std::process::exit(0),
                     _ => eprintln!("{}", error),
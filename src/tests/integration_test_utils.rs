@@ -1,10 +1,10 @@
 #![cfg(test)]
 
-use std::fs::File;
+use std::fs::{remove_file, File};
 use std::io::{BufReader, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use bytelines::ByteLines;
 use itertools;
 
 use crate::ansi;
@@ -91,11 +91,27 @@ pub fn get_line_of_code_from_delta(
 pub fn run_delta(input: &str, config: &config::Config) -> String {
     let mut writer: Vec<u8> = Vec::new();
 
-    delta(
-        ByteLines::new(BufReader::new(input.as_bytes())),
-        &mut writer,
-        &config,
-    )
-    .unwrap();
+    delta(BufReader::new(input.as_bytes()), &mut writer, &config).unwrap();
     String::from_utf8(writer).unwrap()
 }
+
+/// Run delta on `input` with config given in gitconfig syntax, typically a `[delta]` section as
+/// it would appear in a user's ~/.gitconfig. Unlike passing command line arguments, this exercises
+/// the translation of gitconfig entries to `Config`.
+pub fn run_delta_with_config_str(input: &str, config_str: &str) -> String {
+    static GIT_CONFIG_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let git_config_path = std::env::temp_dir().join(format!(
+        "delta__run_delta_with_config_str_{}_{}.gitconfig",
+        std::process::id(),
+        GIT_CONFIG_FILE_COUNTER.fetch_add(1, Ordering::SeqCst)
+    ));
+    let git_config_path = git_config_path.to_str().unwrap();
+    let config = make_config_from_args_and_git_config(
+        &[],
+        Some(config_str.as_bytes()),
+        Some(git_config_path),
+    );
+    let output = run_delta(input, &config);
+    remove_file(git_config_path).unwrap();
+    output
+}
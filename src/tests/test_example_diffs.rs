@@ -46,6 +46,31 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_config_str_is_equivalent_to_args() {
+        let output = integration_test_utils::run_delta_with_config_str(
+            RENAMED_FILE_INPUT,
+            "
+[delta]
+    file-renamed-label = moved:
+    file-style = bold yellow
+    line-numbers = true
+",
+        );
+        let config = integration_test_utils::make_config_from_args(&[
+            "--file-renamed-label",
+            "moved:",
+            "--file-style",
+            "bold yellow",
+            "--line-numbers",
+        ]);
+        assert_eq!(
+            output,
+            integration_test_utils::run_delta(RENAMED_FILE_INPUT, &config)
+        );
+        assert!(strip_ansi_codes(&output).contains("\nmoved: a.py ⟶   b.py\n"));
+    }
+
     #[test]
     fn test_copied_file() {
         let config = integration_test_utils::make_config_from_args(&[]);
@@ -219,6 +244,19 @@ mod tests {
         assert!(output.contains("\nSubmodule x/y/z contains untracked content\n"));
     }
 
+    #[test]
+    fn test_submodule_style() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--submodule-style",
+            "red",
+            "--file-decoration-style",
+            "none",
+        ]);
+        let output = integration_test_utils::run_delta(SUBMODULE_DIFF_LOG, &config);
+        let painted_line = ansi_term::Color::Red.paint("Submodule submoduleB 0ffa700..0c8b00d:");
+        assert!(output.contains(&painted_line.to_string()));
+    }
+
     #[test]
     fn test_triple_dash_at_beginning_of_line_in_code() {
         let config = integration_test_utils::make_config_from_args(&[]);
@@ -236,6 +274,28 @@ mod tests {
         assert!(output.contains("Binary files /dev/null and b/foo differ\n"));
     }
 
+    #[test]
+    fn test_suppress_diff_binary_notice() {
+        let config =
+            integration_test_utils::make_config_from_args(&["--suppress-diff-binary-notice"]);
+        let output = integration_test_utils::run_delta(BINARY_FILES_DIFFER, &config);
+        let output = strip_ansi_codes(&output);
+        assert!(!output.contains("Binary files"));
+    }
+
+    #[test]
+    fn test_binary_diff_style() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--binary-diff-style",
+            "red",
+            "--file-decoration-style",
+            "none",
+        ]);
+        let output = integration_test_utils::run_delta(BINARY_FILES_DIFFER, &config);
+        let painted_notice = ansi_term::Color::Red.paint("Binary files /dev/null and b/foo differ");
+        assert!(output.contains(&painted_notice.to_string()));
+    }
+
     #[test]
     fn test_diff_in_diff() {
         let config = integration_test_utils::make_config_from_args(&[]);
@@ -316,6 +376,41 @@ commit 94907c0f136f46dc46ffae2dc92dca9af7eb7c2e
         assert!(output.bytes().all(|b: u8| b != b'\r'));
     }
 
+    #[test]
+    fn test_show_line_endings() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--show-line-endings",
+            "--inspect-raw-lines",
+            "false",
+        ]);
+        let output = integration_test_utils::run_delta(
+            GIT_DIFF_SINGLE_HUNK_WITH_SEQUENCE_OF_CR_ESCAPE_SEQUENCES_LF,
+            &config,
+        );
+        let output = strip_ansi_codes(&output);
+        assert!(output.contains("deleted line↵⬛\n"));
+        assert!(output.contains("println!(\"added line\");↵⬛\n"));
+        assert!(output.bytes().all(|b: u8| b != b'\r'));
+    }
+
+    #[test]
+    fn test_show_line_endings_on_plus() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--show-line-endings",
+            "--show-line-endings-on",
+            "plus",
+            "--inspect-raw-lines",
+            "false",
+        ]);
+        let output = integration_test_utils::run_delta(
+            GIT_DIFF_SINGLE_HUNK_WITH_SEQUENCE_OF_CR_ESCAPE_SEQUENCES_LF,
+            &config,
+        );
+        let output = strip_ansi_codes(&output);
+        assert!(output.contains("deleted line\n"));
+        assert!(output.contains("println!(\"added line\");↵⬛\n"));
+    }
+
     #[test]
     fn test_commit_decoration_style_omit() {
         _do_test_commit_style_no_decoration(&[
@@ -1486,6 +1581,138 @@ src/align.rs:71: impl<'a> Alignment<'a> { │
         }
     }
 
+    #[test]
+    fn test_empty_line_style() {
+        for (example_diff, base_style_name, empty_line_style_name) in &[
+            (
+                DIFF_WITH_REMOVED_EMPTY_LINE,
+                "--minus-style",
+                "--minus-empty-line-style",
+            ),
+            (
+                DIFF_WITH_ADDED_EMPTY_LINE,
+                "--plus-style",
+                "--plus-empty-line-style",
+            ),
+        ] {
+            let config = integration_test_utils::make_config_from_args(&[
+                *base_style_name,
+                "normal green",
+                *empty_line_style_name,
+                "normal blue",
+            ]);
+            let output = integration_test_utils::run_delta(example_diff, &config);
+            let line = output.lines().nth(8).unwrap();
+            let style = style::Style::from_str("normal blue", None, None, true, false);
+            assert_eq!(
+                line,
+                &style
+                    .ansi_term_style
+                    .paint(ansi::ANSI_CSI_CLEAR_TO_EOL)
+                    .to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_file_path_regex_transform() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--file-path-regex-transform",
+            "^src/=source/",
+            "--hunk-header-style",
+            "file line-number syntax",
+        ]);
+        let output = integration_test_utils::run_delta(GIT_DIFF_SINGLE_HUNK, &config);
+        let output = strip_ansi_codes(&output);
+        assert!(output.contains("\nsource/align.rs\n"));
+        assert!(output.contains("source/align.rs:71:"));
+        assert!(!output.contains("src/align.rs"));
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--file-path-regex-transform",
+            "^src/(.*)$=$1",
+            "--file-path-regex-transform",
+            r"\.rs$=.rust",
+        ]);
+        let output = integration_test_utils::run_delta(GIT_DIFF_SINGLE_HUNK, &config);
+        let output = strip_ansi_codes(&output);
+        assert!(output.contains("\nalign.rust\n"));
+    }
+
+    #[test]
+    fn test_redact_lines() {
+        for args in &[
+            vec!["--redact-lines", "password|token"],
+            vec!["--redact-lines", "password", "--redact-lines", "token"],
+            vec![
+                "--redact-lines",
+                "token",
+                "--redact-lines",
+                "password",
+                "--side-by-side",
+            ],
+        ] {
+            let config = integration_test_utils::make_config_from_args(args);
+            let output = integration_test_utils::run_delta(DIFF_WITH_SECRETS, &config);
+            let output = strip_ansi_codes(&output);
+            assert!(!output.contains("hunter"));
+            assert!(!output.contains("abc123"));
+            assert_eq!(output.matches("[REDACTED]").count(), 3);
+            assert!(output.contains("user = bob"));
+        }
+
+        let config = integration_test_utils::make_config_from_args(&[
+            "--redact-lines",
+            "password",
+            "--redact-placeholder",
+            "***",
+            "--redact-style",
+            "red",
+        ]);
+        let output = integration_test_utils::run_delta(DIFF_WITH_SECRETS, &config);
+        let style = style::Style::from_str("red", None, None, true, false);
+        assert!(output.contains(&style.paint("***").to_string()));
+        assert_eq!(strip_ansi_codes(&output).matches("***").count(), 2);
+    }
+
+    #[test]
+    fn test_file_added_and_deleted_line_style() {
+        let config = integration_test_utils::make_config_from_args(&[
+            "--file-added-line-style",
+            "blue",
+            "--file-deleted-line-style",
+            "yellow",
+        ]);
+        let output = integration_test_utils::run_delta(ADDED_AND_DELETED_FILES_DIFF, &config);
+        let line_number = |text: &str| {
+            strip_ansi_codes(&output)
+                .lines()
+                .position(|line| line == text)
+                .unwrap()
+        };
+        ansi_test_utils::assert_line_has_style(
+            &output,
+            line_number("added line"),
+            "added line",
+            "blue",
+            &config,
+        );
+        ansi_test_utils::assert_line_has_style(
+            &output,
+            line_number("deleted line"),
+            "deleted line",
+            "yellow",
+            &config,
+        );
+        ansi_test_utils::assert_line_does_not_have_style(
+            &output,
+            line_number("modified line"),
+            "modified line",
+            "blue",
+            &config,
+        );
+    }
+
     #[test]
     fn test_whitespace_error() {
         let whitespace_error_style = "bold yellow red ul";
@@ -1630,6 +1857,37 @@ src/align.rs:71: impl<'a> Alignment<'a> { │
         assert!(output.contains("a b ⟶   c d\n"));
     }
 
+    #[test]
+    fn test_suppress_empty_hunk_context() {
+        const DIFF: &str = "\
+diff --git a/a.py b/a.py
+index 8e37a9e..6ce4863 100644
+--- a/a.py
++++ b/a.py
+@@ -1,2 +1,2 @@ def f():
+ x = 1
+ y = 2
+@@ -10,3 +10,3 @@ def g():
+ z = 3
+-w = 4
++w = 5
+";
+        let config = integration_test_utils::make_config_from_args(&[]);
+        let output = integration_test_utils::run_delta(DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        assert!(output.contains("def f():"));
+        assert!(output.contains("x = 1\ny = 2\n"));
+
+        let config =
+            integration_test_utils::make_config_from_args(&["--suppress-empty-hunk-context"]);
+        let output = integration_test_utils::run_delta(DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        assert!(!output.contains("def f():"));
+        assert!(!output.contains("x = 1"));
+        assert!(output.contains("def g():"));
+        assert!(output.contains("z = 3\nw = 4\nw = 5\n"));
+    }
+
     const GIT_DIFF_SINGLE_HUNK: &str = "\
 commit 94907c0f136f46dc46ffae2dc92dca9af7eb7c2e
 Author: Dan Davison <dandavison7@gmail.com>
@@ -2224,6 +2482,42 @@ index 0000000..c6c830c
 +  private Long id;
 +  private String name;
 +}
+";
+
+    const DIFF_WITH_SECRETS: &str = "\
+diff --git a/config.ini b/config.ini
+index 8b1cc6a..b4fc2ce 100644
+--- a/config.ini
++++ b/config.ini
+@@ -1,3 +1,3 @@
+ user = bob
+-password = hunter1
++password = hunter2
+ token = abc123
+";
+
+    const ADDED_AND_DELETED_FILES_DIFF: &str = "\
+diff --git a/added.txt b/added.txt
+new file mode 100644
+index 0000000..8b1cc6a
+--- /dev/null
++++ b/added.txt
+@@ -0,0 +1 @@
++added line
+diff --git a/deleted.txt b/deleted.txt
+deleted file mode 100644
+index 8b1cc6a..0000000
+--- a/deleted.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-deleted line
+diff --git a/modified.txt b/modified.txt
+index 8b1cc6a..b4fc2ce 100644
+--- a/modified.txt
++++ b/modified.txt
+@@ -1 +1,2 @@
+ unchanged line
++modified line
 ";
 
     const DIFF_WITH_WHITESPACE_ERROR: &str = r"
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use syntect::highlighting::Style as SyntectStyle;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -5,9 +7,7 @@ use crate::config::INLINE_SYMBOL_WIDTH_1;
 
 use crate::config::Config;
 use crate::delta::State;
-use crate::features::line_numbers;
 use crate::features::line_numbers::SideBySideLineWidth;
-use crate::features::side_by_side::available_line_width;
 use crate::features::side_by_side::line_is_too_long;
 use crate::features::side_by_side::LineSegments;
 use crate::features::side_by_side::{Left, Right};
@@ -20,15 +20,70 @@ pub struct WrapConfig {
     pub left_symbol: String,
     pub right_symbol: String,
     pub right_prefix_symbol: String,
+    // The (painted) --wrap-overflow-symbol, None if the truncation symbol is used instead.
+    pub overflow_symbol: Option<String>,
     // In fractions of 1000 so that a >100 wide panel can
     // still be configured down to a single character.
     pub use_wrap_right_permille: usize,
     // This value is --wrap-max-lines + 1, and unlimited is 0, see
     // adapt_wrap_max_lines_argument()
     pub max_lines: usize,
+    pub newline_behavior: WrapNewlineBehavior,
     pub inline_hint_syntect_style: SyntectStyle,
 }
 
+/// What happens to the newline at the end of a line which is wrapped,
+/// see [`handle_newline_in_wrap`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WrapNewlineBehavior {
+    /// The newline is kept but does not take up any space.
+    Absorb,
+    /// A `↵` (which does take up space) is placed in front of the newline.
+    Visible,
+    /// The newline is removed.
+    Strip,
+}
+
+/// Symbol placed in front of the newline by [`WrapNewlineBehavior::Visible`].
+const NEWLINE_SYMBOL: &str = "↵";
+
+fn is_newline(text: &str) -> bool {
+    text == "\n" || text == "\r\n"
+}
+
+/// Apply `behavior` to the trailing `\n` or `\r\n` of the line `segments`. The
+/// newline is split off the last segment so that [`wrap_line`] can keep it on the
+/// current line even if that line is full, as it does not take up any space.
+fn handle_newline_in_wrap<'a, S: Copy>(
+    segments: &mut LineSegments<'a, S>,
+    behavior: WrapNewlineBehavior,
+    symbol_style: S,
+) {
+    if behavior == WrapNewlineBehavior::Absorb {
+        return;
+    }
+    let (style, text) = match segments.pop() {
+        Some(segment) => segment,
+        None => return,
+    };
+    let newline_pos = if text.ends_with("\r\n") {
+        text.len() - 2
+    } else if text.ends_with('\n') {
+        text.len() - 1
+    } else {
+        segments.push((style, text));
+        return;
+    };
+    let (body, newline) = text.split_at(newline_pos);
+    if !body.is_empty() {
+        segments.push((style, body));
+    }
+    if behavior == WrapNewlineBehavior::Visible {
+        segments.push((symbol_style, NEWLINE_SYMBOL));
+        segments.push((style, newline));
+    }
+}
+
 /// Wrap the given `line` if it is longer than `line_width`. Wrap to at most
 /// [Config::WrapConfig::max_lines](WrapConfig::max_lines) lines,
 /// then truncate again - but never truncate if it is `0`. Place
@@ -40,6 +95,8 @@ pub struct WrapConfig {
 /// on the next line [right_prefix_symbol](WrapConfig::right_prefix_symbol).
 /// The inserted characters will follow the
 /// [inline_hint_syntect_style](WrapConfig::inline_hint_syntect_style).
+/// The trailing newline is handled according to
+/// [newline_behavior](WrapConfig::newline_behavior).
 ///
 /// The input `line` is expected to start with an (ultimately not printed) `+`, `-` or ` ` prefix.
 /// The prefix `_` is also added to the start of wrapped lines.
@@ -100,6 +157,38 @@ where
             }
         }
     }
+    impl<'a, S: Copy + Default> CurrLine<'a, S> {
+        // A newline does not take up any space, so it is absorbed into the current line even if
+        // that line is full. Push `text`, which would make the line `new_len` long, if only a
+        // newline overhangs `max_len`: either a single newline which is all that is left on the
+        // `stack`, or the newline at the end of `text` when it is the last segment.
+        // Return whether `text` was pushed.
+        fn push_absorbing_newline(
+            &mut self,
+            text: (S, &'a str),
+            new_len: usize,
+            max_len: usize,
+            stack: &mut Vec<(S, &'a str)>,
+        ) -> bool {
+            match stack.last() {
+                Some(&(next_style, nl))
+                    if new_len == max_len && stack.len() == 1 && is_newline(nl) =>
+                {
+                    self.push_and_set_len(text, new_len);
+                    // Do not count the '\n'
+                    self.push_and_set_len((next_style, nl), new_len);
+                    stack.pop();
+                    true
+                }
+                None if new_len == max_len + 1 && text.1.ends_with('\n') => {
+                    // Do not count the included '\n': - 1
+                    self.push_and_set_len(text, new_len - 1);
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
 
     // The first `push_and_set_len` will include the "+/-/ " prefix, subsequent
     // `reset()` add `LINEPREFIX`. Thus each line starts with a prefix.
@@ -114,7 +203,10 @@ where
         None => *fill_style,
     };
 
-    let mut stack = line.into_iter().rev().collect::<Vec<_>>();
+    let mut segments = line.into_iter().collect::<Vec<_>>();
+    handle_newline_in_wrap(&mut segments, wrap_config.newline_behavior, symbol_style);
+
+    let mut stack = segments.into_iter().rev().collect::<Vec<_>>();
 
     let line_limit_reached = |result: &Vec<_>| {
         // If only the wrap symbol and no extra text fits, then wrapping is not possible.
@@ -138,35 +230,12 @@ where
         let must_split = if new_len < max_len {
             curr_line.push_and_set_len((style, text), new_len);
             false
-        } else if new_len == max_len {
-            match stack.last() {
-                // Perfect fit, no need to make space for a `wrap_symbol`.
-                None => {
-                    curr_line.push_and_set_len((style, text), new_len);
-                    false
-                }
-                #[allow(clippy::identity_op)]
-                // A single '\n' left on the stack can be pushed onto the current line.
-                Some((next_style, nl)) if stack.len() == 1 && *nl == "\n" => {
-                    curr_line.push_and_set_len((style, text), new_len);
-                    // Do not count the '\n': + 0
-                    curr_line.push_and_set_len((*next_style, *nl), new_len + 0);
-                    stack.pop();
-                    false
-                }
-                _ => true,
-            }
-        } else if new_len == max_len + 1 && stack.is_empty() {
-            // If the one overhanging char is '\n' then keep it on the current line.
-            if text.ends_with('\n') {
-                // Do not count the included '\n': - 1
-                curr_line.push_and_set_len((style, text), new_len - 1);
-                false
-            } else {
-                true
-            }
+        } else if new_len == max_len && stack.is_empty() {
+            // Perfect fit, no need to make space for a `wrap_symbol`.
+            curr_line.push_and_set_len((style, text), new_len);
+            false
         } else {
-            true
+            !curr_line.push_absorbing_newline((style, text), new_len, max_len, &mut stack)
         };
 
         // Text must be split, one part (or just `wrap_symbol`) is added to the
@@ -284,28 +353,96 @@ where
     (size_prev, wrapped.len())
 }
 
+/// The alignment passed to [`wrap_minusplus_block`] does not match the lines it was given.
+#[derive(Debug, PartialEq)]
+pub struct WrapAlignmentError {
+    pub message: &'static str,
+    pub errhint: String,
+}
+
+impl WrapAlignmentError {
+    fn new(message: &'static str, errhint: &str) -> Self {
+        Self {
+            message,
+            errhint: errhint.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for WrapAlignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.message, self.errhint)
+    }
+}
+
+/// Check that `alignment` refers to the lines of `syntax`, `diff` and `wrapinfo` in order, as
+/// expected by [`wrap_minusplus_block`].
+fn check_alignment(
+    syntax: &MinusPlus<Vec<LineSegments<SyntectStyle>>>,
+    diff: &MinusPlus<Vec<LineSegments<Style>>>,
+    alignment: &[(Option<usize>, Option<usize>)],
+    wrapinfo: &MinusPlus<Vec<bool>>,
+) -> Result<(), WrapAlignmentError> {
+    let mut expected = MinusPlus::new(0, 0);
+    let mut check = |side: MinusPlusIndex, index: usize, errhint: &str| {
+        if index != expected[side] {
+            Err(WrapAlignmentError::new("bad alignment index", errhint))
+        } else if index >= wrapinfo[side].len() {
+            Err(WrapAlignmentError::new("bad wrap info", errhint))
+        } else if index >= syntax[side].len() {
+            Err(WrapAlignmentError::new("bad syntax alignment", errhint))
+        } else if index >= diff[side].len() {
+            Err(WrapAlignmentError::new("bad diff alignment", errhint))
+        } else {
+            expected[side] += 1;
+            Ok(())
+        }
+    };
+    for (minus, plus) in alignment {
+        match (minus, plus) {
+            (Some(m), None) => check(Left, *m, "[*l*] (-)")?,
+            (None, Some(p)) => check(Right, *p, "(-) [*r*]")?,
+            (Some(m), Some(p)) => {
+                check(Left, *m, "[*l*] (r)")?;
+                check(Right, *p, "(l) [*r*]")?;
+            }
+            (None, None) => return Err(WrapAlignmentError::new("None-None alignment", "")),
+        }
+    }
+    Ok(())
+}
+
 /// Call [`wrap_line`] for the `syntax` and the `diff` lines if `wrapinfo` says
 /// a specific line was longer than `line_width`. Return an adjusted `alignment`
-/// with regard to the added wrapped lines.
+/// with regard to the added wrapped lines, or an error if `alignment` is
+/// inconsistent with the `syntax`, `diff` or `wrapinfo` data. The lines are only
+/// taken out of `syntax` and `diff` once `alignment` has been checked, so these
+/// are left unchanged when an inconsistent `alignment` is reported.
 #[allow(clippy::comparison_chain, clippy::type_complexity)]
 pub fn wrap_minusplus_block<'c: 'a, 'a>(
     config: &'c Config,
-    syntax: MinusPlus<Vec<LineSegments<'a, SyntectStyle>>>,
-    diff: MinusPlus<Vec<LineSegments<'a, Style>>>,
+    syntax: &mut MinusPlus<Vec<LineSegments<'a, SyntectStyle>>>,
+    diff: &mut MinusPlus<Vec<LineSegments<'a, Style>>>,
     alignment: &[(Option<usize>, Option<usize>)],
     line_width: &SideBySideLineWidth,
     wrapinfo: &'a MinusPlus<Vec<bool>>,
-) -> (
-    Vec<(Option<usize>, Option<usize>)>,
-    MinusPlus<Vec<State>>,
-    MinusPlus<Vec<LineSegments<'a, SyntectStyle>>>,
-    MinusPlus<Vec<LineSegments<'a, Style>>>,
-) {
+) -> Result<
+    (
+        Vec<(Option<usize>, Option<usize>)>,
+        MinusPlus<Vec<State>>,
+        MinusPlus<Vec<LineSegments<'a, SyntectStyle>>>,
+        MinusPlus<Vec<LineSegments<'a, Style>>>,
+    ),
+    WrapAlignmentError,
+> {
     let mut new_alignment = Vec::new();
     let mut new_states = MinusPlus::<Vec<State>>::default();
     let mut new_wrapped_syntax = MinusPlus::default();
     let mut new_wrapped_diff = MinusPlus::default();
 
+    check_alignment(syntax, diff, alignment, wrapinfo)?;
+    let (syntax, diff) = (std::mem::take(syntax), std::mem::take(diff));
+
     // Turn all these into pairs of iterators so they can be advanced according
     // to the alignment and independently.
     let mut syntax = MinusPlus::new(syntax.minus.into_iter(), syntax.plus.into_iter());
@@ -327,7 +464,7 @@ pub fn wrap_minusplus_block<'c: 'a, 'a>(
         line_width: usize,
         fill_style: &Style,
         errhint: &'a str,
-    ) -> (usize, usize)
+    ) -> Result<(usize, usize), WrapAlignmentError>
     where
         ItSyn: Iterator<Item = LineSegments<'a, SyntectStyle>>,
         ItDiff: Iterator<Item = LineSegments<'a, Style>>,
@@ -335,14 +472,14 @@ pub fn wrap_minusplus_block<'c: 'a, 'a>(
     {
         let must_wrap = *wrapinfo_iter
             .next()
-            .unwrap_or_else(|| panic!("bad wrap info {}", errhint));
+            .ok_or_else(|| WrapAlignmentError::new("bad wrap info", errhint))?;
 
         let (start, extended_to) = wrap_if_too_long(
             config,
             wrapped_syntax,
             syntax_iter
                 .next()
-                .unwrap_or_else(|| panic!("bad syntax alignment {}", errhint)),
+                .ok_or_else(|| WrapAlignmentError::new("bad syntax alignment", errhint))?,
             must_wrap,
             line_width,
             &config.null_syntect_style,
@@ -367,7 +504,7 @@ pub fn wrap_minusplus_block<'c: 'a, 'a>(
             wrapped_diff,
             diff_iter
                 .next()
-                .unwrap_or_else(|| panic!("bad diff alignment {}", errhint)),
+                .ok_or_else(|| WrapAlignmentError::new("bad diff alignment", errhint))?,
             must_wrap,
             line_width,
             fill_style,
@@ -376,20 +513,22 @@ pub fn wrap_minusplus_block<'c: 'a, 'a>(
 
         // The underlying text is the same for the style and diff, so
         // the length of the wrapping should be identical:
-        assert_eq!(
-            (start, extended_to),
-            (start2, extended_to2),
-            "syntax and diff wrapping differs {}",
-            errhint
-        );
+        if (start, extended_to) != (start2, extended_to2) {
+            return Err(WrapAlignmentError::new(
+                "syntax and diff wrapping differs",
+                errhint,
+            ));
+        }
 
-        (start, extended_to)
+        Ok((start, extended_to))
     }
 
     // This macro avoids having the same code block 4x in the alignment processing
     macro_rules! wrap_and_assert {
         ($side:tt, $errhint:tt, $have:tt, $expected:tt) => {{
-            assert_eq!(*$have, $expected, "bad alignment index {}", $errhint);
+            if *$have != $expected {
+                return Err(WrapAlignmentError::new("bad alignment index", $errhint));
+            }
             $expected += 1;
 
             wrap_syntax_and_diff(
@@ -402,7 +541,7 @@ pub fn wrap_minusplus_block<'c: 'a, 'a>(
                 line_width[$side],
                 &fill_style[$side],
                 $errhint,
-            )
+            )?
         }};
     }
 
@@ -461,7 +600,9 @@ pub fn wrap_minusplus_block<'c: 'a, 'a>(
 
                 (minus_extended, plus_extended)
             }
-            _ => unreachable!("None-None alignment"),
+            (None, None) => {
+                return Err(WrapAlignmentError::new("None-None alignment", ""));
+            }
         };
 
         if minus_extended > 0 {
@@ -478,40 +619,75 @@ pub fn wrap_minusplus_block<'c: 'a, 'a>(
         }
     }
 
-    (
+    Ok((
         new_alignment,
         new_states,
         new_wrapped_syntax,
         new_wrapped_diff,
-    )
+    ))
 }
 
-#[allow(clippy::comparison_chain, clippy::type_complexity)]
+/// The states and style sections of an unchanged line, wrapped to the width of one panel.
+pub type WrappedZeroLine<'a> = (
+    Vec<State>,
+    Vec<LineSegments<'a, SyntectStyle>>,
+    Vec<LineSegments<'a, Style>>,
+);
+
+/// Wrap an unchanged line for display in both side-by-side panels, given the `line_width` of
+/// each panel. The panels should be equally sized, but the remaining panel width might differ due
+/// to the space the line numbers take up, so the line is wrapped separately for each panel. The
+/// panel with fewer wrapped lines is then padded with empty lines when painting.
+/// Return the line for the left panel, and the line for the right panel if it differs from that.
 pub fn wrap_zero_block<'c: 'a, 'a>(
     config: &'c Config,
     raw_line: &str,
-    mut states: Vec<State>,
+    states: Vec<State>,
     syntax_style_sections: Vec<LineSegments<'a, SyntectStyle>>,
     diff_style_sections: Vec<LineSegments<'a, Style>>,
-    line_numbers_data: &Option<&mut line_numbers::LineNumbersData>,
-) -> (
-    Vec<State>,
-    Vec<LineSegments<'a, SyntectStyle>>,
-    Vec<LineSegments<'a, Style>>,
-) {
-    // The width is the minimum of the left/right side. The panels should be equally sized,
-    // but in rare cases the remaining panel width might differ due to the space the line
-    // numbers take up.
-    let line_width = if let Some(line_numbers_data) = line_numbers_data {
-        let width = available_line_width(config, line_numbers_data);
-        std::cmp::min(width[Left], width[Right])
-    } else {
-        std::cmp::min(
-            config.side_by_side_data[Left].width,
-            config.side_by_side_data[Right].width,
-        )
-    };
+    line_width: SideBySideLineWidth,
+) -> (WrappedZeroLine<'a>, Option<WrappedZeroLine<'a>>) {
+    // The right panel only needs its own lines if they can differ from those of the left panel.
+    if line_width[Left] == line_width[Right]
+        || !line_is_too_long(raw_line, line_width[Left].min(line_width[Right]))
+    {
+        let line = wrap_zero_line(
+            config,
+            raw_line,
+            states,
+            syntax_style_sections,
+            diff_style_sections,
+            line_width[Left],
+        );
+        return (line, None);
+    }
+    let right = wrap_zero_line(
+        config,
+        raw_line,
+        states.clone(),
+        syntax_style_sections.clone(),
+        diff_style_sections.clone(),
+        line_width[Right],
+    );
+    let left = wrap_zero_line(
+        config,
+        raw_line,
+        states,
+        syntax_style_sections,
+        diff_style_sections,
+        line_width[Left],
+    );
+    (left, Some(right))
+}
 
+fn wrap_zero_line<'c: 'a, 'a>(
+    config: &'c Config,
+    raw_line: &str,
+    mut states: Vec<State>,
+    syntax_style_sections: Vec<LineSegments<'a, SyntectStyle>>,
+    diff_style_sections: Vec<LineSegments<'a, Style>>,
+    line_width: usize,
+) -> WrappedZeroLine<'a> {
     // Called with a single line, so no need to use the 1-sized bool vector.
     // If that changes the wrapping logic should be updated as well.
     debug_assert_eq!(diff_style_sections.len(), 1);
@@ -565,10 +741,14 @@ mod tests {
     use syntect::highlighting::Style as SyntectStyle;
 
     use super::wrap_line;
+    use super::wrap_minusplus_block;
+    use super::WrapAlignmentError;
     use super::WrapConfig;
+    use super::WrapNewlineBehavior;
     use crate::ansi::strip_ansi_codes;
     use crate::config::Config;
     use crate::features::side_by_side::LineSegments;
+    use crate::minusplus::MinusPlus;
     use crate::style::Style;
     use crate::tests::integration_test_utils::{make_config_from_args, run_delta};
 
@@ -832,6 +1012,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wrap_line_newline_behavior() {
+        for nl in &["\n", "\r\n"] {
+            let text = format!("56789{}", nl);
+            let line = vec![(*S1, "_01234"), (*S2, text.as_str())];
+
+            let absorb = mk_wrap_cfg(&TEST_WRAP_CFG);
+            let lines = wrap_test(&absorb, line.clone(), 6);
+            assert_eq!(
+                lines,
+                vec![
+                    vec![(*S1, "_01234"), (*SD, W)],
+                    vec![(*SD, "_"), (*S2, text.as_str())]
+                ]
+            );
+
+            let visible = mk_wrap_cfg(&WrapConfig {
+                newline_behavior: WrapNewlineBehavior::Visible,
+                ..TEST_WRAP_CFG.clone()
+            });
+            let lines = wrap_test(&visible, line.clone(), 6);
+            assert_eq!(
+                lines,
+                vec![
+                    vec![(*S1, "_01234"), (*SD, W)],
+                    vec![(*SD, "_"), (*S2, "56789"), (*SD, "↵"), (*S2, *nl)]
+                ]
+            );
+
+            let strip = mk_wrap_cfg(&WrapConfig {
+                newline_behavior: WrapNewlineBehavior::Strip,
+                ..TEST_WRAP_CFG.clone()
+            });
+            let lines = wrap_test(&strip, line, 6);
+            assert_eq!(
+                lines,
+                vec![
+                    vec![(*S1, "_01234"), (*SD, W)],
+                    vec![(*SD, "_"), (*S2, "56789")]
+                ]
+            );
+        }
+    }
+
     #[test]
     fn test_wrap_line_unicode() {
         let cfg = mk_wrap_cfg(&TEST_WRAP_CFG);
@@ -919,16 +1143,50 @@ index 223ca50..e69de29 100644
         let output = strip_ansi_codes(&output);
         let lines: Vec<_> = output.lines().skip(crate::config::HEADER_LEN).collect();
         let expected = vec![
-            "│L│abcdefghijklm+   │RRRR│abcdefghijklm+",
-            "│L│nopqrstuvwxzy+   │RRRR│nopqrstuvwxzy+",
-            "│L│ 0123456789 0+   │RRRR│ 0123456789 0+",
-            "│L│123456789 012+   │RRRR│123456789 012+",
-            "│L│3456789 01234567>│RRRR│3456789 01234>",
+            "│L│abcdefghijklmnop+│RRRR│abcdefghijklm+",
+            "│L│qrstuvwxzy 01234+│RRRR│nopqrstuvwxzy+",
+            "│L│56789 0123456789+│RRRR│ 0123456789 0+",
+            "│L│ 0123456789 0123+│RRRR│123456789 012+",
+            "│L│456789 0123456789│RRRR│3456789 01234>",
             "│L│a = 1            │RRRR│a = 2         ",
         ];
         assert_eq!(lines, expected);
     }
 
+    #[test]
+    fn test_wrap_overflow_symbol() {
+        let args = default_wrap_cfg_plus(&[
+            "--side-by-side",
+            "--line-numbers-left-format",
+            "│L│",
+            "--line-numbers-right-format",
+            "│RRRR│",
+            "--width",
+            "40",
+            "--line-fill-method",
+            "spaces",
+            "--wrap-overflow-symbol",
+            "#",
+        ]);
+        let mut config = make_config_from_args(&args);
+        config.truncation_symbol = ">".into();
+
+        let output = run_delta(HUNK_ZERO_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let lines: Vec<_> = output.lines().skip(crate::config::HEADER_LEN).collect();
+        assert_eq!(lines[4], "│L│456789 0123456789│RRRR│3456789 01234#");
+
+        // Without wrapping, long lines are truncated as usual.
+        let mut config = make_config_from_args(&[&args[..], &["--wrap-max-lines", "0"]].concat());
+        config.truncation_symbol = ">".into();
+
+        let output = run_delta(HUNK_ZERO_DIFF, &config);
+        let output = strip_ansi_codes(&output);
+        let lines: Vec<_> = output.lines().skip(crate::config::HEADER_LEN).collect();
+        assert!(lines[0].ends_with('>'));
+        assert!(!output.contains('#'));
+    }
+
     #[test]
     fn test_wrap_with_large_hunk_zero_line_numbers() {
         let mut config = make_config_from_args(&default_wrap_cfg_plus(&[
@@ -948,11 +1206,11 @@ index 223ca50..e69de29 100644
         let output = strip_ansi_codes(&output);
         let lines: Vec<_> = output.lines().skip(crate::config::HEADER_LEN).collect();
         let expected = vec![
-            "│LLL│abcde+                   │WW   10   +- 101999 WW│abcde+",
-            "│LLL│fghij+                   │WW        +-        WW│fghij+",
-            "│LLL│klmno+                   │WW        +-        WW│klmno+",
-            "│LLL│pqrst+                   │WW        +-        WW│pqrst+",
-            "│LLL│uvwxzy 0123456789 012345>│WW        +-        WW│uvwxz>",
+            "│LLL│abcdefghijklmnopqrstuvwx+│WW   10   +- 101999 WW│abcde+",
+            "│LLL│zy 0123456789 0123456789+│WW        +-        WW│fghij+",
+            "│LLL│ 0123456789 0123456789 0+│WW        +-        WW│klmno+",
+            "│LLL│123456789                │WW        +-        WW│pqrst+",
+            "│LLL│                         │WW        +-        WW│uvwxz>",
             "│LLL│a = 1                    │WW        +- 102000 WW│a = 2",
         ];
         assert_eq!(lines, expected);
@@ -1131,4 +1389,35 @@ index 223ca50..e69de29 100644
             assert_eq!(lines, expected);
         }
     }
+
+    #[test]
+    fn test_wrap_minusplus_block_bad_alignment() {
+        let config = make_config_from_args(&["--side-by-side"]);
+        let mut syntax: MinusPlus<Vec<LineSegments<SyntectStyle>>> =
+            MinusPlus::new(vec![vec![(*SY, "abc")]], vec![]);
+        let mut diff: MinusPlus<Vec<LineSegments<Style>>> =
+            MinusPlus::new(vec![vec![(*SD, "abc")]], vec![]);
+        let wrapinfo = MinusPlus::new(vec![false], vec![]);
+        let line_width = MinusPlus::new(10, 10);
+
+        // The alignment refers to a second minus line which does not exist.
+        let result = wrap_minusplus_block(
+            &config,
+            &mut syntax,
+            &mut diff,
+            &[(Some(0), None), (Some(1), None)],
+            &line_width,
+            &wrapinfo,
+        );
+        assert_eq!(
+            result.err(),
+            Some(WrapAlignmentError {
+                message: "bad wrap info",
+                errhint: "[*l*] (-)".to_string(),
+            })
+        );
+        // The lines are left in place for the caller to fall back to.
+        assert_eq!(syntax, MinusPlus::new(vec![vec![(*SY, "abc")]], vec![]));
+        assert_eq!(diff, MinusPlus::new(vec![vec![(*SD, "abc")]], vec![]));
+    }
 }